@@ -0,0 +1,147 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use triton_vm::fri_domain::FriDomain;
+use triton_vm::table::base_table::{InheritsFromTable, TableLike};
+use triton_vm::table::hash_table::{self, HashTable};
+use triton_vm::table::table_collection::derive_omicron;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::traits::{GetRandomElements, PrimitiveRootOfUnity};
+
+/// Compares the column-major `low_degree_extension` to the row-major `low_degree_extend_all` on
+/// the widest base table. Both compute the same codewords, so the difference is the cost of the
+/// transposition into rows.
+fn low_degree_extension(c: &mut Criterion) {
+    let mut group = c.benchmark_group("low_degree_extension");
+    group.sample_size(10);
+
+    let num_trace_randomizers = 2;
+    for log_2_padded_height in [6, 8] {
+        let padded_height: usize = 1 << log_2_padded_height;
+        let mut rng = rand::thread_rng();
+        let matrix = (0..padded_height)
+            .map(|_| BFieldElement::random_elements(hash_table::BASE_WIDTH, &mut rng))
+            .collect();
+        let table = HashTable::new_prover(matrix);
+        let omicron = derive_omicron(padded_height as u64);
+        let fri_domain_length = 4 * (padded_height + num_trace_randomizers).next_power_of_two();
+        let fri_domain = FriDomain {
+            offset: BFieldElement::generator(),
+            omega: BFieldElement::primitive_root_of_unity(fri_domain_length as u64).unwrap(),
+            length: fri_domain_length,
+        };
+
+        group.bench_function(BenchmarkId::new("column_major", padded_height), |bencher| {
+            bencher.iter(|| {
+                table.low_degree_extension_or_panic(
+                    &fri_domain,
+                    omicron,
+                    padded_height,
+                    num_trace_randomizers,
+                    0..table.base_width(),
+                )
+            })
+        });
+        group.bench_function(BenchmarkId::new("row_major", padded_height), |bencher| {
+            bencher.iter(|| {
+                table.low_degree_extend_all(
+                    &fri_domain,
+                    omicron,
+                    padded_height,
+                    num_trace_randomizers,
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Interpolation of all columns of the widest base table with trace randomizers, where every
+/// column's randomized trace is built before interpolation.
+fn interpolate_columns(c: &mut Criterion) {
+    let mut group = c.benchmark_group("interpolate_columns");
+    group.sample_size(10);
+
+    let num_trace_randomizers = 16;
+    for log_2_padded_height in [6, 8] {
+        let padded_height: usize = 1 << log_2_padded_height;
+        let mut rng = rand::thread_rng();
+        let matrix = (0..padded_height)
+            .map(|_| BFieldElement::random_elements(hash_table::BASE_WIDTH, &mut rng))
+            .collect();
+        let table = HashTable::new_prover(matrix);
+        let omicron = derive_omicron(padded_height as u64);
+        let fri_domain_length = 4 * (padded_height + num_trace_randomizers).next_power_of_two();
+        let fri_domain = FriDomain {
+            offset: BFieldElement::generator(),
+            omega: BFieldElement::primitive_root_of_unity(fri_domain_length as u64).unwrap(),
+            length: fri_domain_length,
+        };
+
+        group.bench_function(BenchmarkId::new("base_columns", padded_height), |bencher| {
+            bencher.iter(|| {
+                table.interpolate_columns_or_panic(
+                    &fri_domain,
+                    omicron,
+                    padded_height,
+                    num_trace_randomizers,
+                    table.base_columns(),
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Interpolation of 40 columns with one parallel task per column, compared to splitting the
+/// columns evenly across the available threads.
+fn interpolate_columns_in_chunks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("interpolate_columns_in_chunks");
+    group.sample_size(10);
+
+    let num_columns: usize = 40;
+    let num_trace_randomizers = 16;
+    let padded_height: usize = 1 << 8;
+    let mut rng = rand::thread_rng();
+    let matrix = (0..padded_height)
+        .map(|_| BFieldElement::random_elements(hash_table::BASE_WIDTH, &mut rng))
+        .collect();
+    let table = HashTable::new_prover(matrix);
+    let columns = (0..num_columns).collect::<Vec<_>>();
+    let omicron = derive_omicron(padded_height as u64);
+    let fri_domain_length = 4 * (padded_height + num_trace_randomizers).next_power_of_two();
+    let fri_domain = FriDomain {
+        offset: BFieldElement::generator(),
+        omega: BFieldElement::primitive_root_of_unity(fri_domain_length as u64).unwrap(),
+        length: fri_domain_length,
+    };
+
+    let chunk_size_per_thread = num_columns.div_ceil(rayon::current_num_threads());
+    for (name, chunk_size) in [
+        ("per_column", None),
+        ("chunked", Some(chunk_size_per_thread)),
+    ] {
+        group.bench_function(BenchmarkId::new(name, num_columns), |bencher| {
+            bencher.iter(|| {
+                table
+                    .interpolate_selected_columns_in_chunks(
+                        &fri_domain,
+                        omicron,
+                        padded_height,
+                        num_trace_randomizers,
+                        &columns,
+                        chunk_size,
+                        &mut rand::thread_rng(),
+                    )
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    low_degree_extension,
+    interpolate_columns,
+    interpolate_columns_in_chunks
+);
+criterion_main!(benches);
@@ -1,17 +1,22 @@
 use itertools::Itertools;
 use num_traits::{One, Zero};
-use std::ops::Mul;
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Mul, Range};
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::mpolynomial::Degree;
 use twenty_first::shared_math::traits::{FiniteField, Inverse};
 use twenty_first::shared_math::x_field_element::XFieldElement;
 
 use crate::fri_domain::FriDomain;
+use crate::table::base_table::BaseTableError;
 use crate::table::processor_table::PROCESSOR_TABLE_NUM_PERMUTATION_ARGUMENTS;
 use crate::table::table_collection::TableId::{
     HashTable, InstructionTable, ProcessorTable, ProgramTable,
 };
-use crate::table::table_collection::{interpolant_degree, ExtTableCollection, TableId};
+use crate::table::table_collection::{ExtTableCollection, TableId, NUM_TABLES};
 use crate::table::table_column::{
     HashExtTableColumn, InstructionExtTableColumn, JumpStackExtTableColumn, OpStackExtTableColumn,
     ProcessorExtTableColumn, ProgramExtTableColumn, RamExtTableColumn,
@@ -22,6 +27,44 @@ pub const NUM_PRIVATE_EVAL_ARGS: usize = 3;
 pub const NUM_CROSS_TABLE_ARGS: usize = NUM_PRIVATE_PERM_ARGS + NUM_PRIVATE_EVAL_ARGS;
 pub const NUM_PUBLIC_EVAL_ARGS: usize = 2;
 
+/// The number of values of a terminal quotient `terminal_quotient_iter` computes at once. Large
+/// enough to amortize the batch inversion of the zerofier.
+pub const TERMINAL_QUOTIENT_BATCH_SIZE: usize = 1 << 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermArgError {
+    SelfReferential { table: TableId, column: usize },
+    MissingTable(TableId),
+    Table(BaseTableError),
+}
+
+impl Error for PermArgError {}
+
+impl Display for PermArgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PermArgError::SelfReferential { table, column } => write!(
+                f,
+                "Permutation Argument links column {column} of {table} to itself, \
+                which is trivially satisfied"
+            ),
+            PermArgError::MissingTable(table_id) => {
+                write!(
+                    f,
+                    "Permutation Argument links {table_id}, which holds no data"
+                )
+            }
+            PermArgError::Table(err) => write!(f, "Permutation Argument is malformed: {err}"),
+        }
+    }
+}
+
+impl From<BaseTableError> for PermArgError {
+    fn from(err: BaseTableError) -> Self {
+        PermArgError::Table(err)
+    }
+}
+
 pub trait CrossTableArg {
     fn from(&self) -> (TableId, usize);
     fn to(&self) -> (TableId, usize);
@@ -38,47 +81,155 @@ pub trait CrossTableArg {
     where
         Self: Sized;
 
+    /// The codeword of the argument's `from` side, i.e., the left-hand side of the difference.
+    fn lhs_codeword<'a>(
+        &self,
+        ext_codeword_tables: &'a ExtTableCollection,
+    ) -> Cow<'a, [XFieldElement]> {
+        let (from_table, from_column) = self.from();
+        Cow::Borrowed(&ext_codeword_tables.data(from_table)[from_column])
+    }
+
+    /// The codeword of the argument's `to` side, i.e., the right-hand side of the difference.
+    fn rhs_codeword<'a>(
+        &self,
+        ext_codeword_tables: &'a ExtTableCollection,
+    ) -> Cow<'a, [XFieldElement]> {
+        let (to_table, to_column) = self.to();
+        Cow::Borrowed(&ext_codeword_tables.data(to_table)[to_column])
+    }
+
+    /// The entries in `range` of `lhs_codeword`, computed without building the entire codeword.
+    fn lhs_codeword_chunk<'a>(
+        &self,
+        ext_codeword_tables: &'a ExtTableCollection,
+        range: Range<usize>,
+    ) -> Cow<'a, [XFieldElement]> {
+        let (from_table, from_column) = self.from();
+        Cow::Borrowed(&ext_codeword_tables.data(from_table)[from_column][range])
+    }
+
+    /// The entries in `range` of `rhs_codeword`, computed without building the entire codeword.
+    fn rhs_codeword_chunk<'a>(
+        &self,
+        ext_codeword_tables: &'a ExtTableCollection,
+        range: Range<usize>,
+    ) -> Cow<'a, [XFieldElement]> {
+        let (to_table, to_column) = self.to();
+        Cow::Borrowed(&ext_codeword_tables.data(to_table)[to_column][range])
+    }
+
+    /// The value of the argument's `from` side in the given `cross_table_slice`.
+    fn lhs_value(&self, cross_table_slice: &[Vec<XFieldElement>]) -> XFieldElement {
+        let (from_table, from_column) = self.from();
+        table_row(cross_table_slice, from_table)[from_column]
+    }
+
+    /// The value of the argument's `to` side in the given `cross_table_slice`.
+    fn rhs_value(&self, cross_table_slice: &[Vec<XFieldElement>]) -> XFieldElement {
+        let (to_table, to_column) = self.to();
+        table_row(cross_table_slice, to_table)[to_column]
+    }
+
+    /// The point in which the argument's two sides must agree, i.e., the root of the zerofier of
+    /// `terminal_quotient`. By default, this is `omicron^-1`, the domain value of the last row.
+    fn zerofier_point(&self, omicron: XFieldElement) -> XFieldElement {
+        omicron.inverse()
+    }
+
     fn terminal_quotient(
         &self,
         ext_codeword_tables: &ExtTableCollection,
         fri_domain: &FriDomain<XFieldElement>,
         omicron: XFieldElement,
     ) -> Vec<XFieldElement> {
-        let (from_table, from_column) = self.from();
-        let (to_table, to_column) = self.to();
-        let lhs_codeword = &ext_codeword_tables.data(from_table)[from_column];
-        let rhs_codeword = &ext_codeword_tables.data(to_table)[to_column];
-        let zerofier = fri_domain
-            .domain_values()
-            .into_iter()
-            .map(|x| x - omicron.inverse())
-            .collect();
-        let zerofier_inverse = XFieldElement::batch_inversion(zerofier);
+        self.terminal_quotient_iter(ext_codeword_tables, fri_domain, omicron)
+            .collect()
+    }
 
-        zerofier_inverse
-            .into_iter()
-            .zip_eq(lhs_codeword.iter().zip_eq(rhs_codeword.iter()))
-            .map(|(z, (&from, &to))| (from - to) * z)
-            .collect_vec()
+    /// Like `terminal_quotient`, but lazily yields the values of the quotient codeword. The values
+    /// are computed in batches of `TERMINAL_QUOTIENT_BATCH_SIZE`, see `terminal_quotient_chunks`.
+    /// Callers that immediately fold or hash the quotient never hold the entire codeword.
+    fn terminal_quotient_iter<'a>(
+        &'a self,
+        ext_codeword_tables: &'a ExtTableCollection,
+        fri_domain: &'a FriDomain<XFieldElement>,
+        omicron: XFieldElement,
+    ) -> Box<dyn Iterator<Item = XFieldElement> + 'a> {
+        let values = self
+            .terminal_quotient_chunks(
+                ext_codeword_tables,
+                fri_domain,
+                omicron,
+                TERMINAL_QUOTIENT_BATCH_SIZE,
+            )
+            .flatten();
+        Box::new(values)
+    }
+
+    /// Like `terminal_quotient`, but lazily yields the quotient codeword in consecutive chunks of
+    /// length `chunk_size`, the last chunk possibly being shorter. Each chunk's zerofier and both
+    /// sides of the argument are only computed when that chunk is requested, bounding the memory
+    /// needed to `chunk_size`.
+    fn terminal_quotient_chunks<'a>(
+        &'a self,
+        ext_codeword_tables: &'a ExtTableCollection,
+        fri_domain: &'a FriDomain<XFieldElement>,
+        omicron: XFieldElement,
+        chunk_size: usize,
+    ) -> Box<dyn Iterator<Item = Vec<XFieldElement>> + 'a> {
+        assert!(chunk_size > 0, "Chunks must not be empty");
+        let zerofier_point = self.zerofier_point(omicron);
+        let chunks = (0..fri_domain.length)
+            .step_by(chunk_size)
+            .map(move |chunk_start| {
+                let chunk_end = fri_domain.length.min(chunk_start + chunk_size);
+                terminal_quotient_chunk(
+                    self,
+                    ext_codeword_tables,
+                    fri_domain,
+                    zerofier_point,
+                    chunk_start..chunk_end,
+                )
+            });
+        Box::new(chunks)
     }
 
+    /// The degree bound of `terminal_quotient`. Both sides of the argument are (weighted sums of)
+    /// column interpolants, so their difference has degree at most the tables' shared
+    /// interpolant degree. The difference vanishes in the `zerofier_point`, and dividing by the
+    /// degree-1 zerofier lowers the degree by exactly one.
     fn quotient_degree_bound(
         &self,
         ext_codeword_tables: &ExtTableCollection,
         num_trace_randomizers: usize,
     ) -> Degree {
-        let interpolant_degree =
-            interpolant_degree(ext_codeword_tables.padded_height, num_trace_randomizers);
-        interpolant_degree - 1
+        ext_codeword_tables.interpolant_degree(num_trace_randomizers) - 1
     }
 
     fn evaluate_difference(&self, cross_table_slice: &[Vec<XFieldElement>]) -> XFieldElement {
-        let (from_table, from_column) = self.from();
-        let (to_table, to_column) = self.to();
-        let lhs = cross_table_slice[from_table as usize][from_column];
-        let rhs = cross_table_slice[to_table as usize][to_column];
+        self.lhs_value(cross_table_slice) - self.rhs_value(cross_table_slice)
+    }
 
-        lhs - rhs
+    /// Whether the argument's values in the last rows of the `from` and `to` tables both equal
+    /// the `expected_terminal`. Catches arguments that hold pointwise but are wired to the wrong
+    /// terminal. `ext_tables` must hold the extended trace, not codewords.
+    fn terminals_match(
+        &self,
+        ext_tables: &ExtTableCollection,
+        expected_terminal: XFieldElement,
+    ) -> bool {
+        let (from_table, _) = self.from();
+        let (to_table, _) = self.to();
+        let mut last_rows = vec![vec![]; NUM_TABLES];
+        for table_id in [from_table, to_table] {
+            match ext_tables.data(table_id).last() {
+                Some(row) => last_rows[table_id.index()] = row.clone(),
+                None => return false,
+            }
+        }
+        self.lhs_value(&last_rows) == expected_terminal
+            && self.rhs_value(&last_rows) == expected_terminal
     }
 
     fn verify_with_public_data(
@@ -94,21 +245,75 @@ pub trait CrossTableArg {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// A Permutation Argument between the weighted sums of one or more columns of two tables.
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct PermArg {
     from_table: TableId,
-    from_column: usize,
+    from_columns: Vec<usize>,
     to_table: TableId,
-    to_column: usize,
+    to_columns: Vec<usize>,
+    weights: Vec<XFieldElement>,
+    boundary: Option<XFieldElement>,
 }
 
 impl CrossTableArg for PermArg {
+    /// The `from` table and its first column.
     fn from(&self) -> (TableId, usize) {
-        (self.from_table, self.from_column)
+        (self.from_table, self.from_columns[0])
     }
 
+    /// The `to` table and its first column.
     fn to(&self) -> (TableId, usize) {
-        (self.to_table, self.to_column)
+        (self.to_table, self.to_columns[0])
+    }
+
+    fn lhs_codeword<'a>(
+        &self,
+        ext_codeword_tables: &'a ExtTableCollection,
+    ) -> Cow<'a, [XFieldElement]> {
+        let codewords = ext_codeword_tables.data(self.from_table);
+        weighted_column_sum(codewords, &self.from_columns, &self.weights)
+    }
+
+    fn rhs_codeword<'a>(
+        &self,
+        ext_codeword_tables: &'a ExtTableCollection,
+    ) -> Cow<'a, [XFieldElement]> {
+        let codewords = ext_codeword_tables.data(self.to_table);
+        weighted_column_sum(codewords, &self.to_columns, &self.weights)
+    }
+
+    fn lhs_codeword_chunk<'a>(
+        &self,
+        ext_codeword_tables: &'a ExtTableCollection,
+        range: Range<usize>,
+    ) -> Cow<'a, [XFieldElement]> {
+        let codewords = ext_codeword_tables.data(self.from_table);
+        weighted_column_sum_in(codewords, &self.from_columns, &self.weights, range)
+    }
+
+    fn rhs_codeword_chunk<'a>(
+        &self,
+        ext_codeword_tables: &'a ExtTableCollection,
+        range: Range<usize>,
+    ) -> Cow<'a, [XFieldElement]> {
+        let codewords = ext_codeword_tables.data(self.to_table);
+        weighted_column_sum_in(codewords, &self.to_columns, &self.weights, range)
+    }
+
+    fn lhs_value(&self, cross_table_slice: &[Vec<XFieldElement>]) -> XFieldElement {
+        let row = table_row(cross_table_slice, self.from_table);
+        weighted_row_sum(row, &self.from_columns, &self.weights)
+    }
+
+    fn rhs_value(&self, cross_table_slice: &[Vec<XFieldElement>]) -> XFieldElement {
+        let row = table_row(cross_table_slice, self.to_table);
+        weighted_row_sum(row, &self.to_columns, &self.weights)
+    }
+
+    /// The `boundary` the argument is anchored at, or `omicron^-1` if none was set.
+    fn zerofier_point(&self, omicron: XFieldElement) -> XFieldElement {
+        self.boundary.unwrap_or_else(|| omicron.inverse())
     }
 
     fn default_initial() -> XFieldElement {
@@ -135,53 +340,256 @@ impl PermArg {
         to_table: TableId,
         to_column: usize,
     ) -> Self {
+        Self::new_multi(
+            from_table,
+            vec![from_column],
+            to_table,
+            vec![to_column],
+            vec![XFieldElement::one()],
+        )
+    }
+
+    /// Like `new`, but fails if the `from` and `to` side are the same column of the same table. A
+    /// Permutation Argument of a column against itself is trivially satisfied.
+    pub fn try_new(
+        from_table: TableId,
+        from_column: usize,
+        to_table: TableId,
+        to_column: usize,
+    ) -> Result<Self, PermArgError> {
+        if from_table == to_table && from_column == to_column {
+            return Err(PermArgError::SelfReferential {
+                table: from_table,
+                column: from_column,
+            });
+        }
+        Ok(Self::new(from_table, from_column, to_table, to_column))
+    }
+
+    /// Like `new`, but takes anything convertible to a column index, e.g., the column enums of
+    /// the respective tables.
+    pub fn between(
+        from_table: TableId,
+        from_column: impl Into<usize>,
+        to_table: TableId,
+        to_column: impl Into<usize>,
+    ) -> Self {
+        Self::new(from_table, from_column.into(), to_table, to_column.into())
+    }
+
+    /// A Permutation Argument between the sums of the `from_columns` and of the `to_columns`,
+    /// where the `i`-th column on either side is weighted by `weights[i]`.
+    pub fn new_multi(
+        from_table: TableId,
+        from_columns: Vec<usize>,
+        to_table: TableId,
+        to_columns: Vec<usize>,
+        weights: Vec<XFieldElement>,
+    ) -> Self {
+        assert!(
+            !weights.is_empty(),
+            "A Permutation Argument needs at least one column"
+        );
+        assert_eq!(
+            weights.len(),
+            from_columns.len(),
+            "Need one weight per `from` column"
+        );
+        assert_eq!(
+            weights.len(),
+            to_columns.len(),
+            "Need one weight per `to` column"
+        );
+        debug_assert!(
+            from_table != to_table || from_columns != to_columns,
+            "A Permutation Argument must not link {from_table} columns {from_columns:?} to themselves"
+        );
         PermArg {
             from_table,
-            from_column,
+            from_columns,
             to_table,
-            to_column,
+            to_columns,
+            weights,
+            boundary: None,
         }
     }
+
+    /// Anchor the argument at the domain value `boundary` instead of the last row's domain value
+    /// `omicron^-1`, i.e., require the two running products to agree in `boundary`.
+    #[must_use]
+    pub fn with_boundary(mut self, boundary: XFieldElement) -> Self {
+        self.boundary = Some(boundary);
+        self
+    }
+
+    /// The domain value the argument is anchored at, if it differs from `omicron^-1`.
+    pub fn boundary(&self) -> Option<XFieldElement> {
+        self.boundary
+    }
+
+    /// The `from` table and all of its columns linked by this argument. See `CrossTableArg::from`
+    /// for only the first column.
+    pub fn from_columns(&self) -> (TableId, &[usize]) {
+        (self.from_table, &self.from_columns)
+    }
+
+    /// The `to` table and all of its columns linked by this argument.
+    pub fn to_columns(&self) -> (TableId, &[usize]) {
+        (self.to_table, &self.to_columns)
+    }
+
+    /// The weights of the linked columns, in the same order as the columns.
+    pub fn weights(&self) -> &[XFieldElement] {
+        &self.weights
+    }
+
+    /// Like `terminal_quotient`, but fails with a descriptive error instead of panicking if one
+    /// of the linked tables holds no codewords or lacks one of the linked columns.
+    pub fn try_terminal_quotient(
+        &self,
+        ext_codeword_tables: &ExtTableCollection,
+        fri_domain: &FriDomain<XFieldElement>,
+        omicron: XFieldElement,
+    ) -> Result<Vec<XFieldElement>, PermArgError> {
+        for (table_id, columns) in [self.from_columns(), self.to_columns()] {
+            if ext_codeword_tables.try_data(table_id).is_none() {
+                return Err(PermArgError::MissingTable(table_id));
+            }
+            for &column in columns {
+                ext_codeword_tables.try_column(table_id, column)?;
+            }
+        }
+        Ok(self.terminal_quotient(ext_codeword_tables, fri_domain, omicron))
+    }
+
+    /// Like `terminal_quotient`, but in debug builds, interpolates the quotient and asserts that
+    /// its degree does not exceed this argument's `quotient_degree_bound`. Catches a miscomputed
+    /// bound as soon as any quotient exceeds it. In release builds, the check and its
+    /// interpolation are compiled out.
+    pub fn terminal_quotient_with_degree_check(
+        &self,
+        ext_codeword_tables: &ExtTableCollection,
+        fri_domain: &FriDomain<XFieldElement>,
+        omicron: XFieldElement,
+        num_trace_randomizers: usize,
+    ) -> Vec<XFieldElement> {
+        let quotient_degree_bound =
+            self.quotient_degree_bound(ext_codeword_tables, num_trace_randomizers);
+        self.terminal_quotient_with_degree_bound(
+            ext_codeword_tables,
+            fri_domain,
+            omicron,
+            quotient_degree_bound,
+        )
+    }
+
+    /// Like `terminal_quotient_with_degree_check`, but checks against the given
+    /// `quotient_degree_bound` instead of the argument's own.
+    fn terminal_quotient_with_degree_bound(
+        &self,
+        ext_codeword_tables: &ExtTableCollection,
+        fri_domain: &FriDomain<XFieldElement>,
+        omicron: XFieldElement,
+        quotient_degree_bound: Degree,
+    ) -> Vec<XFieldElement> {
+        let quotient = self.terminal_quotient(ext_codeword_tables, fri_domain, omicron);
+        if cfg!(debug_assertions) {
+            self.assert_quotient_degree(&quotient, fri_domain, quotient_degree_bound);
+        }
+        quotient
+    }
+
+    /// Panics if the interpolant of the `quotient` codeword exceeds the `quotient_degree_bound`.
+    fn assert_quotient_degree(
+        &self,
+        quotient: &[XFieldElement],
+        fri_domain: &FriDomain<XFieldElement>,
+        quotient_degree_bound: Degree,
+    ) {
+        let quotient_degree = fri_domain.interpolate(quotient).degree() as Degree;
+        assert!(
+            quotient_degree <= quotient_degree_bound,
+            "Quotient of Permutation Argument from {} to {} has degree {quotient_degree}, \
+            exceeding its bound {quotient_degree_bound}",
+            self.from_table,
+            self.to_table,
+        );
+    }
+
+    /// The difference of the argument's two sides in every row, i.e., `evaluate_difference` for
+    /// every row of the `from` and `to` table. The first non-zero entry is the first row where
+    /// the running products diverge. `ext_tables` must hold the extended trace, not codewords.
+    pub fn difference_trace(&self, ext_tables: &ExtTableCollection) -> Vec<XFieldElement> {
+        let from_rows = ext_tables.data(self.from_table);
+        let to_rows = ext_tables.data(self.to_table);
+        from_rows
+            .iter()
+            .zip_eq(to_rows.iter())
+            .map(|(from_row, to_row)| {
+                weighted_row_sum(from_row, &self.from_columns, &self.weights)
+                    - weighted_row_sum(to_row, &self.to_columns, &self.weights)
+            })
+            .collect()
+    }
+
     /// A Permutation Argument between Processor Table and Instruction Table.
     pub fn processor_instruction_perm_arg() -> Self {
-        Self::new(
+        Self::between(
             TableId::ProcessorTable,
-            ProcessorExtTableColumn::InstructionTablePermArg.into(),
+            ProcessorExtTableColumn::InstructionTablePermArg,
             TableId::InstructionTable,
-            InstructionExtTableColumn::RunningProductPermArg.into(),
+            InstructionExtTableColumn::RunningProductPermArg,
         )
     }
 
     /// A Permutation Argument between Processor Table and Jump-Stack Table.
     pub fn processor_jump_stack_perm_arg() -> Self {
-        Self::new(
+        Self::between(
             TableId::ProcessorTable,
-            ProcessorExtTableColumn::JumpStackTablePermArg.into(),
+            ProcessorExtTableColumn::JumpStackTablePermArg,
             TableId::JumpStackTable,
-            JumpStackExtTableColumn::RunningProductPermArg.into(),
+            JumpStackExtTableColumn::RunningProductPermArg,
         )
     }
 
     /// A Permutation Argument between Processor Table and Op-Stack Table.
     pub fn processor_op_stack_perm_arg() -> Self {
-        Self::new(
+        Self::between(
             TableId::ProcessorTable,
-            ProcessorExtTableColumn::OpStackTablePermArg.into(),
+            ProcessorExtTableColumn::OpStackTablePermArg,
             TableId::OpStackTable,
-            OpStackExtTableColumn::RunningProductPermArg.into(),
+            OpStackExtTableColumn::RunningProductPermArg,
         )
     }
 
     /// A Permutation Argument between Processor Table and RAM Table.
     pub fn processor_ram_perm_arg() -> Self {
-        Self::new(
+        Self::between(
             TableId::ProcessorTable,
-            ProcessorExtTableColumn::RamTablePermArg.into(),
+            ProcessorExtTableColumn::RamTablePermArg,
             TableId::RamTable,
-            RamExtTableColumn::RunningProductPermArg.into(),
+            RamExtTableColumn::RunningProductPermArg,
         )
     }
 
+    /// The `terminal_quotient` of every Permutation Argument in `all_permutation_arguments`.
+    /// Shares the expensive inversion of the zerofier across all arguments, which are all anchored
+    /// at `omicron^-1`. The quotients are
+    /// computed in parallel, but returned in the order of `all_permutation_arguments`.
+    pub fn all_quotients(
+        ext_codeword_tables: &ExtTableCollection,
+        fri_domain: &FriDomain<XFieldElement>,
+        omicron: XFieldElement,
+    ) -> Vec<Vec<XFieldElement>> {
+        let zerofier_inverse = terminal_zerofier_inverse(fri_domain, omicron);
+        Self::all_permutation_arguments()
+            .par_iter()
+            .map(|arg| {
+                terminal_quotient_with_zerofier_inverse(arg, ext_codeword_tables, &zerofier_inverse)
+            })
+            .collect()
+    }
+
     pub fn all_permutation_arguments() -> [Self; NUM_PRIVATE_PERM_ARGS] {
         [
             Self::processor_instruction_perm_arg(),
@@ -266,7 +674,7 @@ impl EvalArg {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct GrandCrossTableArg {
     program_to_instruction: EvalArg,
     processor_to_instruction: PermArg,
@@ -383,12 +791,10 @@ impl GrandCrossTableArg {
 
         // cross-table arguments
         for (arg, weight) in self.into_iter() {
-            let (from_table, from_column) = arg.from();
-            let (to_table, to_column) = arg.to();
-            let from_codeword = &ext_codeword_tables.data(from_table)[from_column];
-            let to_codeword = &ext_codeword_tables.data(to_table)[to_column];
+            let from_codeword = arg.lhs_codeword(ext_codeword_tables);
+            let to_codeword = arg.rhs_codeword(ext_codeword_tables);
             let non_linear_summand =
-                weighted_difference_codeword(from_codeword, to_codeword, weight);
+                weighted_difference_codeword(&from_codeword, &to_codeword, weight);
             non_linear_sum_codeword =
                 pointwise_addition(non_linear_sum_codeword, non_linear_summand);
         }
@@ -411,12 +817,7 @@ impl GrandCrossTableArg {
             weighted_difference_codeword(from_codeword, &output_terminal_codeword, weight);
         non_linear_sum_codeword = pointwise_addition(non_linear_sum_codeword, non_linear_summand);
 
-        let zerofier = fri_domain
-            .domain_values()
-            .into_iter()
-            .map(|x| x - omicron.inverse())
-            .collect();
-        let zerofier_inverse = XFieldElement::batch_inversion(zerofier);
+        let zerofier_inverse = terminal_zerofier_inverse(fri_domain, omicron);
 
         zerofier_inverse
             .into_iter()
@@ -446,18 +847,174 @@ impl GrandCrossTableArg {
 
         // input
         let (to_table, to_column) = self.input_to_processor;
-        let processor_in = cross_table_slice[to_table as usize][to_column];
+        let processor_in = table_row(cross_table_slice, to_table)[to_column];
         non_linear_sum += self.input_to_processor_weight * (self.input_terminal - processor_in);
 
         // output
         let (from_table, from_colum) = self.processor_to_output;
-        let processor_out = cross_table_slice[from_table as usize][from_colum];
+        let processor_out = table_row(cross_table_slice, from_table)[from_colum];
         non_linear_sum += self.processor_to_output_weight * (processor_out - self.output_terminal);
 
         non_linear_sum
     }
 }
 
+/// The inverse of the zerofier `x - omicron^-1` of all terminal quotients, evaluated over the
+/// `fri_domain`.
+fn terminal_zerofier_inverse(
+    fri_domain: &FriDomain<XFieldElement>,
+    omicron: XFieldElement,
+) -> Vec<XFieldElement> {
+    zerofier_inverse(fri_domain, omicron.inverse())
+}
+
+/// The inverse of the zerofier `x - zerofier_point`, evaluated over the `fri_domain`.
+fn zerofier_inverse(
+    fri_domain: &FriDomain<XFieldElement>,
+    zerofier_point: XFieldElement,
+) -> Vec<XFieldElement> {
+    let zerofier = fri_domain
+        .domain_values()
+        .into_iter()
+        .map(|x| x - zerofier_point)
+        .collect();
+    XFieldElement::batch_inversion(zerofier)
+}
+
+fn terminal_quotient_with_zerofier_inverse<Arg: CrossTableArg + ?Sized>(
+    arg: &Arg,
+    ext_codeword_tables: &ExtTableCollection,
+    zerofier_inverse: &[XFieldElement],
+) -> Vec<XFieldElement> {
+    let lhs_codeword = arg.lhs_codeword(ext_codeword_tables);
+    let rhs_codeword = arg.rhs_codeword(ext_codeword_tables);
+    zerofier_inverse
+        .iter()
+        .zip_eq(lhs_codeword.iter().zip_eq(rhs_codeword.iter()))
+        .map(|(&z, (&from, &to))| (from - to) * z)
+        .collect_vec()
+}
+
+/// The values of the terminal quotient codeword in `range`. Only computes the zerofier and both
+/// sides of the argument in that `range`.
+fn terminal_quotient_chunk<Arg: CrossTableArg + ?Sized>(
+    arg: &Arg,
+    ext_codeword_tables: &ExtTableCollection,
+    fri_domain: &FriDomain<XFieldElement>,
+    zerofier_point: XFieldElement,
+    range: Range<usize>,
+) -> Vec<XFieldElement> {
+    let mut domain_value = fri_domain.domain_value(range.start as u32);
+    let mut zerofier = Vec::with_capacity(range.len());
+    for _ in range.clone() {
+        zerofier.push(domain_value - zerofier_point);
+        domain_value *= fri_domain.omega;
+    }
+    let zerofier_inverse = XFieldElement::batch_inversion(zerofier);
+
+    let lhs_chunk = arg.lhs_codeword_chunk(ext_codeword_tables, range.clone());
+    let rhs_chunk = arg.rhs_codeword_chunk(ext_codeword_tables, range);
+    zerofier_inverse
+        .into_iter()
+        .zip_eq(lhs_chunk.iter().zip_eq(rhs_chunk.iter()))
+        .map(|(z, (&from, &to))| (from - to) * z)
+        .collect_vec()
+}
+
+/// The pointwise sum of the `columns` of `codewords`, each multiplied by the respective weight.
+/// A single column of weight one is borrowed instead of copied.
+fn weighted_column_sum<'a>(
+    codewords: &'a [Vec<XFieldElement>],
+    columns: &[usize],
+    weights: &[XFieldElement],
+) -> Cow<'a, [XFieldElement]> {
+    let length = codewords[columns[0]].len();
+    weighted_column_sum_in(codewords, columns, weights, 0..length)
+}
+
+/// Like `weighted_column_sum`, but only for the entries in `range`.
+fn weighted_column_sum_in<'a>(
+    codewords: &'a [Vec<XFieldElement>],
+    columns: &[usize],
+    weights: &[XFieldElement],
+    range: Range<usize>,
+) -> Cow<'a, [XFieldElement]> {
+    if let ([column], [weight]) = (columns, weights) {
+        if weight.is_one() {
+            return Cow::Borrowed(&codewords[*column][range]);
+        }
+    }
+    let sum = range
+        .map(|i| {
+            columns
+                .iter()
+                .zip_eq(weights.iter())
+                .map(|(&column, &weight)| weight * codewords[column][i])
+                .sum()
+        })
+        .collect_vec();
+    Cow::Owned(sum)
+}
+
+/// The pointwise linear combination of the `codewords`, i.e., `Σ_j weights[j]·codewords[j][i]`
+/// for every index `i`, computed in parallel. Used to fold the contributions of cross-table
+/// arguments, e.g., the `PermArg` quotients, into a combination codeword. Panics if the number of
+/// weights and codewords differ or if the codewords are not of equal length.
+pub fn weighted_sum(
+    codewords: &[Vec<XFieldElement>],
+    weights: &[XFieldElement],
+) -> Vec<XFieldElement> {
+    assert_eq!(
+        codewords.len(),
+        weights.len(),
+        "Need one weight per codeword"
+    );
+    let length = codewords.first().map_or(0, Vec::len);
+    if let Some((index, codeword)) = codewords
+        .iter()
+        .find_position(|codeword| codeword.len() != length)
+    {
+        panic!(
+            "Codeword {index} has length {}, but codeword 0 has length {length}",
+            codeword.len()
+        );
+    }
+    (0..length)
+        .into_par_iter()
+        .map(|i| {
+            codewords
+                .iter()
+                .zip(weights.iter())
+                .map(|(codeword, &weight)| weight * codeword[i])
+                .sum()
+        })
+        .collect()
+}
+
+/// The row of `table` in the `cross_table_slice`, which holds one row per table in the order
+/// given by `TableId::index`.
+pub fn table_row(cross_table_slice: &[Vec<XFieldElement>], table: TableId) -> &[XFieldElement] {
+    let index = table.index();
+    assert!(
+        index < cross_table_slice.len(),
+        "{table} has index {index}, but the cross-table slice only has {} rows",
+        cross_table_slice.len()
+    );
+    &cross_table_slice[index]
+}
+
+fn weighted_row_sum(
+    row: &[XFieldElement],
+    columns: &[usize],
+    weights: &[XFieldElement],
+) -> XFieldElement {
+    columns
+        .iter()
+        .zip_eq(weights.iter())
+        .map(|(&column, &weight)| weight * row[column])
+        .sum()
+}
+
 fn pointwise_addition(left: Vec<XFieldElement>, right: Vec<XFieldElement>) -> Vec<XFieldElement> {
     left.into_iter()
         .zip_eq(right.into_iter())
@@ -481,15 +1038,158 @@ fn weighted_difference_codeword(
 mod permutation_argument_tests {
     use super::*;
     use crate::stark::triton_stark_tests::parse_simulate_pad_extend;
+    use crate::table::base_table::InheritsFromTable;
+    use crate::table::table_collection::{derive_omicron, interpolant_degree};
     use crate::vm::triton_vm_tests::test_hash_nop_nop_lt;
+    use twenty_first::shared_math::polynomial::Polynomial;
+    use twenty_first::shared_math::traits::{GetRandomElements, PrimitiveRootOfUnity};
 
     #[test]
     fn all_permutation_arguments_link_from_processor_table_test() {
         for perm_arg in PermArg::all_permutation_arguments() {
-            assert_eq!(TableId::ProcessorTable, perm_arg.from_table);
+            let (from_table, from_column) = perm_arg.from();
+            assert_eq!(TableId::ProcessorTable, from_table);
+            assert_eq!(
+                (from_table, [from_column].as_slice()),
+                perm_arg.from_columns()
+            );
+            assert_eq!(perm_arg.from_columns().1.len(), perm_arg.weights().len());
+            assert_eq!(perm_arg.to_columns().1.len(), perm_arg.weights().len());
+        }
+    }
+
+    #[test]
+    fn between_accepts_column_enums_test() {
+        let perm_arg = PermArg::between(
+            TableId::ProcessorTable,
+            ProcessorExtTableColumn::RamTablePermArg,
+            TableId::RamTable,
+            RamExtTableColumn::RunningProductPermArg,
+        );
+        assert_eq!(PermArg::processor_ram_perm_arg(), perm_arg);
+        assert_eq!(
+            (
+                TableId::ProcessorTable,
+                usize::from(ProcessorExtTableColumn::RamTablePermArg)
+            ),
+            perm_arg.from()
+        );
+        assert_eq!(
+            PermArg::new(TableId::RamTable, 3, TableId::RamTable, 4),
+            PermArg::between(TableId::RamTable, 3usize, TableId::RamTable, 4usize)
+        );
+    }
+
+    #[test]
+    fn try_new_rejects_self_referential_perm_arg_test() {
+        assert_eq!(
+            Err(PermArgError::SelfReferential {
+                table: TableId::RamTable,
+                column: 3,
+            }),
+            PermArg::try_new(TableId::RamTable, 3, TableId::RamTable, 3)
+        );
+        assert_eq!(
+            Ok(PermArg::new(TableId::RamTable, 3, TableId::RamTable, 4)),
+            PermArg::try_new(TableId::RamTable, 3, TableId::RamTable, 4)
+        );
+        assert_eq!(
+            Ok(PermArg::new(
+                TableId::ProcessorTable,
+                3,
+                TableId::RamTable,
+                3
+            )),
+            PermArg::try_new(TableId::ProcessorTable, 3, TableId::RamTable, 3)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn new_panics_on_self_referential_perm_arg_test() {
+        PermArg::new(TableId::ProcessorTable, 1, TableId::ProcessorTable, 1);
+    }
+
+    #[test]
+    fn table_row_matches_positional_index_test() {
+        let cross_table_slice = (0..NUM_TABLES)
+            .map(|i| vec![XFieldElement::new_const(BFieldElement::new(i as u64))])
+            .collect_vec();
+        for (position, table_id) in TableId::all().into_iter().enumerate() {
+            assert_eq!(
+                cross_table_slice[position].as_slice(),
+                table_row(&cross_table_slice, table_id)
+            );
         }
     }
 
+    #[test]
+    #[should_panic(expected = "HashTable has index 6, but the cross-table slice only has 6 rows")]
+    fn table_row_of_too_short_cross_table_slice_panics_test() {
+        let cross_table_slice = vec![vec![]; NUM_TABLES - 1];
+        table_row(&cross_table_slice, TableId::HashTable);
+    }
+
+    #[test]
+    fn weighted_sum_test() {
+        let xfe = |value: u64| XFieldElement::new_const(BFieldElement::new(value));
+        let codewords = vec![
+            vec![xfe(1), xfe(2), xfe(3)],
+            vec![xfe(10), xfe(20), xfe(30)],
+        ];
+        let weights = [xfe(2), xfe(5)];
+        let expected = vec![xfe(52), xfe(104), xfe(156)];
+        assert_eq!(expected, weighted_sum(&codewords, &weights));
+
+        let x = XFieldElement::new([0, 1, 0].map(BFieldElement::new));
+        let expected = vec![
+            xfe(1) + x * xfe(10),
+            xfe(2) + x * xfe(20),
+            xfe(3) + x * xfe(30),
+        ];
+        assert_eq!(expected, weighted_sum(&codewords, &[xfe(1), x]));
+
+        assert!(weighted_sum(&[], &[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Codeword 1 has length 2, but codeword 0 has length 3")]
+    fn weighted_sum_of_codewords_of_different_lengths_panics_test() {
+        let codewords = vec![vec![XFieldElement::one(); 3], vec![XFieldElement::one(); 2]];
+        weighted_sum(&codewords, &[XFieldElement::one(), XFieldElement::one()]);
+    }
+
+    #[test]
+    fn difference_trace_test() {
+        let padded_height = 4;
+        let mut rng = rand::thread_rng();
+        let column = XFieldElement::random_elements(padded_height, &mut rng);
+        let mut ext_tables = ExtTableCollection::with_padded_height(padded_height);
+        *ext_tables.processor_table.mut_data() = column.iter().map(|&x| vec![x, x]).collect();
+        *ext_tables.ram_table.mut_data() = column.iter().map(|&x| vec![x]).collect();
+
+        let matching_arg = PermArg::new(TableId::ProcessorTable, 1, TableId::RamTable, 0);
+        assert_eq!(
+            vec![XFieldElement::zero(); padded_height],
+            matching_arg.difference_trace(&ext_tables)
+        );
+
+        let divergence = XFieldElement::random_elements(1, &mut rng)[0];
+        ext_tables.processor_table.mut_data()[2][1] += divergence;
+        let mut expected_difference = vec![XFieldElement::zero(); padded_height];
+        expected_difference[2] = divergence;
+        assert_eq!(
+            expected_difference,
+            matching_arg.difference_trace(&ext_tables)
+        );
+        let first_divergence = matching_arg
+            .difference_trace(&ext_tables)
+            .iter()
+            .position(|difference| !difference.is_zero());
+        assert_eq!(Some(2), first_divergence);
+    }
+
     #[test]
     fn all_quotient_degree_bounds_of_grand_cross_table_argument_are_equal_test() {
         let num_trace_randomizers = 10;
@@ -531,4 +1231,617 @@ mod permutation_argument_tests {
             );
         }
     }
+
+    #[test]
+    fn multi_column_perm_arg_with_zero_weight_reduces_to_single_column_test() {
+        let padded_height = 8;
+        let fri_domain_length = 32;
+        let fri_domain = dummy_xfri_domain(fri_domain_length);
+        let omicron = derive_omicron(padded_height as u64);
+
+        let mut rng = rand::thread_rng();
+        let processor_width = usize::from(ProcessorExtTableColumn::RamTablePermArg) + 1;
+        let ram_width = usize::from(RamExtTableColumn::RunningProductPermArg) + 1;
+        let mut ext_codeword_tables = ExtTableCollection::with_padded_height(padded_height);
+        *ext_codeword_tables.processor_table.mut_data() = (0..processor_width)
+            .map(|_| XFieldElement::random_elements(fri_domain_length, &mut rng))
+            .collect();
+        *ext_codeword_tables.ram_table.mut_data() = (0..ram_width)
+            .map(|_| XFieldElement::random_elements(fri_domain_length, &mut rng))
+            .collect();
+
+        let single_column_arg = PermArg::processor_ram_perm_arg();
+        let (_, from_column) = single_column_arg.from();
+        let (_, to_column) = single_column_arg.to();
+        let two_column_arg = PermArg::new_multi(
+            TableId::ProcessorTable,
+            vec![from_column, 0],
+            TableId::RamTable,
+            vec![to_column, 1],
+            vec![XFieldElement::one(), XFieldElement::zero()],
+        );
+        assert_eq!(
+            single_column_arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron),
+            two_column_arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron)
+        );
+
+        let mut cross_table_slice = vec![vec![]; TableId::HashTable as usize + 1];
+        cross_table_slice[TableId::ProcessorTable as usize] =
+            XFieldElement::random_elements(processor_width, &mut rng);
+        cross_table_slice[TableId::RamTable as usize] =
+            XFieldElement::random_elements(ram_width, &mut rng);
+        assert_eq!(
+            single_column_arg.evaluate_difference(&cross_table_slice),
+            two_column_arg.evaluate_difference(&cross_table_slice)
+        );
+
+        let weights = XFieldElement::random_elements(2, &mut rng);
+        let weighted_arg = PermArg::new_multi(
+            TableId::ProcessorTable,
+            vec![from_column, 0],
+            TableId::RamTable,
+            vec![to_column, 1],
+            weights.clone(),
+        );
+        let processor_row = &cross_table_slice[TableId::ProcessorTable as usize];
+        let ram_row = &cross_table_slice[TableId::RamTable as usize];
+        let expected_difference = weights[0] * (processor_row[from_column] - ram_row[to_column])
+            + weights[1] * (processor_row[0] - ram_row[1]);
+        assert_eq!(
+            expected_difference,
+            weighted_arg.evaluate_difference(&cross_table_slice)
+        );
+    }
+
+    #[test]
+    fn single_column_perm_arg_borrows_its_codewords_test() {
+        let padded_height = 8;
+        let fri_domain_length = 16;
+        let mut rng = rand::thread_rng();
+        let processor_width = usize::from(ProcessorExtTableColumn::RamTablePermArg) + 1;
+        let ram_width = usize::from(RamExtTableColumn::RunningProductPermArg) + 1;
+        let mut ext_codeword_tables = ExtTableCollection::with_padded_height(padded_height);
+        *ext_codeword_tables.processor_table.mut_data() = (0..processor_width)
+            .map(|_| XFieldElement::random_elements(fri_domain_length, &mut rng))
+            .collect();
+        *ext_codeword_tables.ram_table.mut_data() = (0..ram_width)
+            .map(|_| XFieldElement::random_elements(fri_domain_length, &mut rng))
+            .collect();
+
+        let single_column_arg = PermArg::processor_ram_perm_arg();
+        let (_, from_column) = single_column_arg.from();
+        let lhs_codeword = single_column_arg.lhs_codeword(&ext_codeword_tables);
+        assert!(matches!(lhs_codeword, Cow::Borrowed(_)));
+        assert!(matches!(
+            single_column_arg.rhs_codeword(&ext_codeword_tables),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            ext_codeword_tables.processor_table.data()[from_column],
+            lhs_codeword.as_ref()
+        );
+
+        let weight = XFieldElement::random_elements(1, &mut rng)[0];
+        let weighted_arg = PermArg::new_multi(
+            TableId::ProcessorTable,
+            vec![from_column],
+            TableId::RamTable,
+            vec![0],
+            vec![weight],
+        );
+        let weighted_codeword = weighted_arg.lhs_codeword(&ext_codeword_tables);
+        assert!(matches!(weighted_codeword, Cow::Owned(_)));
+        let expected_codeword = lhs_codeword.iter().map(|&x| weight * x).collect_vec();
+        assert_eq!(expected_codeword, weighted_codeword.as_ref());
+    }
+
+    #[test]
+    fn try_terminal_quotient_reports_out_of_range_column_test() {
+        let padded_height = 8;
+        let fri_domain_length = 32;
+        let fri_domain = dummy_xfri_domain(fri_domain_length);
+        let omicron = derive_omicron(padded_height as u64);
+
+        let mut rng = rand::thread_rng();
+        let mut ext_codeword_tables = ExtTableCollection::with_padded_height(padded_height);
+        assert_eq!(None, ext_codeword_tables.try_data(TableId::RamTable));
+
+        *ext_codeword_tables.processor_table.mut_data() = (0..3)
+            .map(|_| XFieldElement::random_elements(fri_domain_length, &mut rng))
+            .collect();
+        let arg = PermArg::new(TableId::ProcessorTable, 1, TableId::RamTable, 1);
+        assert_eq!(
+            Err(PermArgError::MissingTable(TableId::RamTable)),
+            arg.try_terminal_quotient(&ext_codeword_tables, &fri_domain, omicron)
+        );
+
+        *ext_codeword_tables.ram_table.mut_data() = (0..2)
+            .map(|_| XFieldElement::random_elements(fri_domain_length, &mut rng))
+            .collect();
+        assert_eq!(
+            Ok(arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron)),
+            arg.try_terminal_quotient(&ext_codeword_tables, &fri_domain, omicron)
+        );
+
+        let out_of_range_arg = PermArg::new(TableId::ProcessorTable, 1, TableId::RamTable, 2);
+        let err = out_of_range_arg
+            .try_terminal_quotient(&ext_codeword_tables, &fri_domain, omicron)
+            .unwrap_err();
+        assert_eq!(
+            PermArgError::Table(BaseTableError::ColumnOutOfBounds {
+                name: "RamTable".to_string(),
+                column: 2,
+                width: 2,
+            }),
+            err
+        );
+        let message = err.to_string();
+        assert!(message.contains("RamTable"), "{message}");
+        assert!(message.contains("width 2"), "{message}");
+    }
+
+    #[test]
+    fn changing_weights_changes_terminal_quotient_linearly_test() {
+        let padded_height = 8;
+        let fri_domain_length = 32;
+        let fri_domain = dummy_xfri_domain(fri_domain_length);
+        let omicron = derive_omicron(padded_height as u64);
+
+        let mut rng = rand::thread_rng();
+        let mut ext_codeword_tables = ExtTableCollection::with_padded_height(padded_height);
+        *ext_codeword_tables.processor_table.mut_data() = (0..2)
+            .map(|_| XFieldElement::random_elements(fri_domain_length, &mut rng))
+            .collect();
+        *ext_codeword_tables.ram_table.mut_data() = (0..2)
+            .map(|_| XFieldElement::random_elements(fri_domain_length, &mut rng))
+            .collect();
+
+        let weighted_arg = |weights: Vec<XFieldElement>| {
+            PermArg::new_multi(
+                TableId::ProcessorTable,
+                vec![0, 1],
+                TableId::RamTable,
+                vec![0, 1],
+                weights,
+            )
+        };
+        let quotient_of =
+            |arg: &PermArg| arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron);
+        let (one, zero) = (XFieldElement::one(), XFieldElement::zero());
+        let first_quotient = quotient_of(&weighted_arg(vec![one, zero]));
+        let second_quotient = quotient_of(&weighted_arg(vec![zero, one]));
+
+        let weights = XFieldElement::random_elements(2, &mut rng);
+        let expected_quotient = first_quotient
+            .iter()
+            .zip_eq(second_quotient.iter())
+            .map(|(&first, &second)| weights[0] * first + weights[1] * second)
+            .collect_vec();
+        assert_eq!(
+            expected_quotient,
+            quotient_of(&weighted_arg(weights.clone()))
+        );
+
+        let other_weights = vec![weights[0] + one, weights[1]];
+        assert_ne!(
+            quotient_of(&weighted_arg(weights)),
+            quotient_of(&weighted_arg(other_weights))
+        );
+    }
+
+    #[test]
+    fn terminal_quotient_respects_quotient_degree_bound_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 4;
+        let fri_domain_length = 64;
+        let fri_domain = dummy_xfri_domain(fri_domain_length);
+        let omicron: XFieldElement = derive_omicron(padded_height as u64);
+
+        // Random interpolants of full degree whose differences vanish in `omicron^-1`, as the
+        // running products of a valid Permutation Argument do.
+        let ext_codeword_tables = low_degree_linked_codewords(
+            padded_height,
+            num_trace_randomizers,
+            &fri_domain,
+            omicron.inverse(),
+            (TableId::ProcessorTable, TableId::RamTable),
+            &[(0, 0), (1, 1), (2, 2)],
+        );
+
+        let mut rng = rand::thread_rng();
+        let single_column_arg = PermArg::new(TableId::ProcessorTable, 1, TableId::RamTable, 1);
+        let multi_column_arg = PermArg::new_multi(
+            TableId::ProcessorTable,
+            vec![0, 1, 2],
+            TableId::RamTable,
+            vec![0, 1, 2],
+            XFieldElement::random_elements(3, &mut rng),
+        );
+        for arg in [single_column_arg, multi_column_arg] {
+            let quotient_degree_bound =
+                arg.quotient_degree_bound(&ext_codeword_tables, num_trace_randomizers);
+            let quotient = arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron);
+            let quotient_degree = fri_domain.interpolate(&quotient).degree() as Degree;
+            assert_eq!(quotient_degree_bound, quotient_degree);
+        }
+    }
+
+    #[test]
+    fn perm_arg_with_boundary_is_low_degree_only_if_codewords_agree_in_boundary_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 2;
+        let fri_domain_length = 32;
+        let fri_domain = dummy_xfri_domain(fri_domain_length);
+        let omicron: XFieldElement = derive_omicron(padded_height as u64);
+
+        // The first row's domain value, where the running products of a Permutation Argument
+        // anchored at the start of the tables agree.
+        let boundary = XFieldElement::one();
+        let ext_codeword_tables = low_degree_linked_codewords(
+            padded_height,
+            num_trace_randomizers,
+            &fri_domain,
+            boundary,
+            (TableId::ProcessorTable, TableId::RamTable),
+            &[(0, 0)],
+        );
+
+        let terminal_arg = PermArg::new(TableId::ProcessorTable, 0, TableId::RamTable, 0);
+        let boundary_arg = terminal_arg.clone().with_boundary(boundary);
+        assert_eq!(None, terminal_arg.boundary());
+        assert_eq!(Some(boundary), boundary_arg.boundary());
+        assert_eq!(boundary, boundary_arg.zerofier_point(omicron));
+
+        let quotient_degree_bound =
+            boundary_arg.quotient_degree_bound(&ext_codeword_tables, num_trace_randomizers);
+        let quotient_degree = |arg: &PermArg| {
+            let quotient = arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron);
+            fri_domain.interpolate(&quotient).degree() as Degree
+        };
+        assert_eq!(quotient_degree_bound, quotient_degree(&boundary_arg));
+
+        // The codewords agree in `1`, but not in `omicron^-1`.
+        assert!(quotient_degree_bound < quotient_degree(&terminal_arg));
+
+        let chunks = boundary_arg
+            .terminal_quotient_chunks(&ext_codeword_tables, &fri_domain, omicron, 5)
+            .concat();
+        assert_eq!(
+            boundary_arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron),
+            chunks
+        );
+    }
+
+    /// Codeword tables in which the `from_table` and the `to_table` hold random interpolants of
+    /// full degree. For every pair of `linked_columns`, the `from_table`'s column and the
+    /// `to_table`'s column agree in the `anchor`, as the two sides of a valid cross-table argument
+    /// do. All other columns up to the largest linked one are unconstrained.
+    fn low_degree_linked_codewords(
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        fri_domain: &FriDomain<XFieldElement>,
+        anchor: XFieldElement,
+        (from_table, to_table): (TableId, TableId),
+        linked_columns: &[(usize, usize)],
+    ) -> ExtTableCollection {
+        let interpolant_degree = interpolant_degree(padded_height, num_trace_randomizers);
+        let mut rng = rand::thread_rng();
+        let mut random_polynomials = |num_polynomials: usize, degree: Degree| {
+            (0..num_polynomials)
+                .map(|_| {
+                    Polynomial::new(XFieldElement::random_elements(
+                        degree as usize + 1,
+                        &mut rng,
+                    ))
+                })
+                .collect_vec()
+        };
+        let from_width = linked_columns.iter().map(|&(from, _)| from + 1).max();
+        let to_width = linked_columns.iter().map(|&(_, to)| to + 1).max();
+        let from_polynomials = random_polynomials(from_width.unwrap_or(0), interpolant_degree);
+        let mut to_polynomials = random_polynomials(to_width.unwrap_or(0), interpolant_degree);
+        let vanishing_factor = Polynomial::new(vec![-anchor, XFieldElement::one()]);
+        for &(from_column, to_column) in linked_columns {
+            let cofactor = random_polynomials(1, interpolant_degree - 1).remove(0);
+            to_polynomials[to_column] =
+                from_polynomials[from_column].clone() + vanishing_factor.clone() * cofactor;
+        }
+
+        let mut ext_codeword_tables = ExtTableCollection::with_padded_height(padded_height);
+        for (table_id, polynomials) in [(from_table, from_polynomials), (to_table, to_polynomials)]
+        {
+            *mut_table_data(&mut ext_codeword_tables, table_id) = polynomials
+                .iter()
+                .map(|polynomial| fri_domain.evaluate(polynomial))
+                .collect();
+        }
+        ext_codeword_tables
+    }
+
+    fn mut_table_data(
+        ext_tables: &mut ExtTableCollection,
+        table_id: TableId,
+    ) -> &mut Vec<Vec<XFieldElement>> {
+        let table = match table_id {
+            TableId::ProgramTable => {
+                &mut ext_tables.program_table as &mut dyn InheritsFromTable<XFieldElement>
+            }
+            TableId::InstructionTable => &mut ext_tables.instruction_table,
+            TableId::ProcessorTable => &mut ext_tables.processor_table,
+            TableId::OpStackTable => &mut ext_tables.op_stack_table,
+            TableId::RamTable => &mut ext_tables.ram_table,
+            TableId::JumpStackTable => &mut ext_tables.jump_stack_table,
+            TableId::HashTable => &mut ext_tables.hash_table,
+        };
+        table.mut_data()
+    }
+
+    #[test]
+    fn terminal_quotient_with_degree_check_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 2;
+        let fri_domain_length = 32;
+        let fri_domain = dummy_xfri_domain(fri_domain_length);
+        let omicron: XFieldElement = derive_omicron(padded_height as u64);
+        let ext_codeword_tables = low_degree_linked_codewords(
+            padded_height,
+            num_trace_randomizers,
+            &fri_domain,
+            omicron.inverse(),
+            (TableId::ProcessorTable, TableId::RamTable),
+            &[(0, 0)],
+        );
+        let arg = PermArg::new(TableId::ProcessorTable, 0, TableId::RamTable, 0);
+
+        assert_eq!(
+            arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron),
+            arg.terminal_quotient_with_degree_check(
+                &ext_codeword_tables,
+                &fri_domain,
+                omicron,
+                num_trace_randomizers
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeding its bound")]
+    #[cfg(debug_assertions)]
+    fn terminal_quotient_with_too_small_degree_bound_panics_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 2;
+        let fri_domain_length = 32;
+        let fri_domain = dummy_xfri_domain(fri_domain_length);
+        let omicron: XFieldElement = derive_omicron(padded_height as u64);
+        let ext_codeword_tables = low_degree_linked_codewords(
+            padded_height,
+            num_trace_randomizers,
+            &fri_domain,
+            omicron.inverse(),
+            (TableId::ProcessorTable, TableId::RamTable),
+            &[(0, 0)],
+        );
+        let arg = PermArg::new(TableId::ProcessorTable, 0, TableId::RamTable, 0);
+
+        let quotient_degree_bound =
+            arg.quotient_degree_bound(&ext_codeword_tables, num_trace_randomizers);
+        arg.terminal_quotient_with_degree_bound(
+            &ext_codeword_tables,
+            &fri_domain,
+            omicron,
+            quotient_degree_bound - 1,
+        );
+    }
+
+    #[test]
+    fn eval_arg_terminal_quotient_is_low_degree_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 2;
+        let fri_domain_length = 32;
+        let fri_domain = dummy_xfri_domain(fri_domain_length);
+        let omicron: XFieldElement = derive_omicron(padded_height as u64);
+
+        // Both running evaluations agree on the terminal, i.e., their difference is a multiple of
+        // the zerofier `x - omicron^-1`.
+        let program_column = usize::from(ProgramExtTableColumn::RunningEvaluation);
+        let instruction_column = usize::from(InstructionExtTableColumn::RunningEvaluation);
+        let ext_codeword_tables = low_degree_linked_codewords(
+            padded_height,
+            num_trace_randomizers,
+            &fri_domain,
+            omicron.inverse(),
+            (TableId::ProgramTable, TableId::InstructionTable),
+            &[(program_column, instruction_column)],
+        );
+
+        let eval_arg = EvalArg::program_instruction_eval_arg();
+        let quotient = eval_arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron);
+        let quotient_degree = fri_domain.interpolate(&quotient).degree() as Degree;
+        assert!(
+            quotient_degree
+                <= eval_arg.quotient_degree_bound(&ext_codeword_tables, num_trace_randomizers)
+        );
+    }
+
+    fn dummy_xfri_domain(length: usize) -> FriDomain<XFieldElement> {
+        FriDomain {
+            offset: BFieldElement::generator().lift(),
+            omega: XFieldElement::primitive_root_of_unity(length as u64).unwrap(),
+            length,
+        }
+    }
+
+    /// Extension codeword tables where all columns involved in a Permutation Argument hold random
+    /// codewords of length `fri_domain_length`.
+    fn random_perm_arg_codeword_tables(
+        padded_height: usize,
+        fri_domain_length: usize,
+    ) -> ExtTableCollection {
+        let mut rng = rand::thread_rng();
+        let mut ext_codeword_tables = ExtTableCollection::with_padded_height(padded_height);
+        for perm_arg in PermArg::all_permutation_arguments() {
+            for (table_id, column) in [perm_arg.from(), perm_arg.to()] {
+                let codewords = mut_table_data(&mut ext_codeword_tables, table_id);
+                while codewords.len() <= column {
+                    codewords.push(XFieldElement::random_elements(fri_domain_length, &mut rng));
+                }
+            }
+        }
+        ext_codeword_tables
+    }
+
+    #[test]
+    fn all_quotients_match_individual_terminal_quotients_test() {
+        let padded_height = 8;
+        let fri_domain = dummy_xfri_domain(32);
+        let omicron = derive_omicron(padded_height as u64);
+        let ext_codeword_tables = random_perm_arg_codeword_tables(padded_height, fri_domain.length);
+
+        let all_quotients = PermArg::all_quotients(&ext_codeword_tables, &fri_domain, omicron);
+        let individual_quotients = PermArg::all_permutation_arguments()
+            .iter()
+            .map(|arg| arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron))
+            .collect_vec();
+        assert_eq!(individual_quotients, all_quotients);
+    }
+
+    #[test]
+    fn all_quotients_match_sequential_reference_test() {
+        let padded_height = 16;
+        let fri_domain = dummy_xfri_domain(64);
+        let omicron = derive_omicron(padded_height as u64);
+        let ext_codeword_tables = random_perm_arg_codeword_tables(padded_height, fri_domain.length);
+
+        let zerofier_inverse = terminal_zerofier_inverse(&fri_domain, omicron);
+        let sequential_quotients = PermArg::all_permutation_arguments()
+            .iter()
+            .map(|arg| {
+                terminal_quotient_with_zerofier_inverse(
+                    arg,
+                    &ext_codeword_tables,
+                    &zerofier_inverse,
+                )
+            })
+            .collect_vec();
+
+        for _ in 0..10 {
+            let parallel_quotients =
+                PermArg::all_quotients(&ext_codeword_tables, &fri_domain, omicron);
+            assert_eq!(sequential_quotients, parallel_quotients);
+        }
+    }
+
+    #[test]
+    fn terminal_quotient_iter_matches_terminal_quotient_test() {
+        let padded_height = 8;
+        let fri_domain = dummy_xfri_domain(4 * TERMINAL_QUOTIENT_BATCH_SIZE);
+        let omicron = derive_omicron(padded_height as u64);
+        let ext_codeword_tables = random_perm_arg_codeword_tables(padded_height, fri_domain.length);
+
+        for perm_arg in PermArg::all_permutation_arguments() {
+            let quotient = perm_arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron);
+            let quotient_iter =
+                perm_arg.terminal_quotient_iter(&ext_codeword_tables, &fri_domain, omicron);
+            assert_eq!(quotient, quotient_iter.collect_vec());
+            let zerofier_inverse = terminal_zerofier_inverse(&fri_domain, omicron);
+            assert_eq!(
+                terminal_quotient_with_zerofier_inverse(
+                    &perm_arg,
+                    &ext_codeword_tables,
+                    &zerofier_inverse
+                ),
+                quotient
+            );
+
+            let sum: XFieldElement = perm_arg
+                .terminal_quotient_iter(&ext_codeword_tables, &fri_domain, omicron)
+                .sum();
+            assert_eq!(quotient.into_iter().sum::<XFieldElement>(), sum);
+        }
+    }
+
+    #[test]
+    fn terminals_match_test() {
+        let padded_height = 4;
+        let perm_arg = PermArg::processor_ram_perm_arg();
+        let (_, processor_column) = perm_arg.from();
+        let (_, ram_column) = perm_arg.to();
+        let terminal = XFieldElement::new([2, 3, 5].map(BFieldElement::new));
+
+        let mut rng = rand::thread_rng();
+        let mut ext_tables = ExtTableCollection::with_padded_height(padded_height);
+        assert!(!perm_arg.terminals_match(&ext_tables, terminal));
+
+        *ext_tables.processor_table.mut_data() = (0..padded_height)
+            .map(|_| XFieldElement::random_elements(processor_column + 1, &mut rng))
+            .collect();
+        *ext_tables.ram_table.mut_data() = (0..padded_height)
+            .map(|_| XFieldElement::random_elements(ram_column + 1, &mut rng))
+            .collect();
+        ext_tables.processor_table.mut_data()[padded_height - 1][processor_column] = terminal;
+        ext_tables.ram_table.mut_data()[padded_height - 1][ram_column] = terminal;
+        assert!(perm_arg.terminals_match(&ext_tables, terminal));
+        assert!(!perm_arg.terminals_match(&ext_tables, terminal + XFieldElement::one()));
+
+        // Agreeing on a terminal other than the expected one is no match either.
+        ext_tables.ram_table.mut_data()[padded_height - 1][ram_column] += XFieldElement::one();
+        assert!(!perm_arg.terminals_match(&ext_tables, terminal));
+        ext_tables.processor_table.mut_data()[padded_height - 1][processor_column] +=
+            XFieldElement::one();
+        assert!(!perm_arg.terminals_match(&ext_tables, terminal));
+    }
+
+    #[test]
+    fn terminal_quotient_chunks_concatenate_to_terminal_quotient_test() {
+        let padded_height = 8;
+        let fri_domain_length = 64;
+        let fri_domain = dummy_xfri_domain(fri_domain_length);
+        let omicron = derive_omicron(padded_height as u64);
+
+        let mut rng = rand::thread_rng();
+        let mut random_codewords = |num_codewords: usize| {
+            (0..num_codewords)
+                .map(|_| XFieldElement::random_elements(fri_domain_length, &mut rng))
+                .collect_vec()
+        };
+        let mut ext_codeword_tables = ExtTableCollection::with_padded_height(padded_height);
+        *ext_codeword_tables.processor_table.mut_data() =
+            random_codewords(usize::from(ProcessorExtTableColumn::RamTablePermArg) + 1);
+        *ext_codeword_tables.ram_table.mut_data() =
+            random_codewords(usize::from(RamExtTableColumn::RunningProductPermArg) + 1);
+
+        let single_column_arg = PermArg::processor_ram_perm_arg();
+        let (_, from_column) = single_column_arg.from();
+        let (_, to_column) = single_column_arg.to();
+        let multi_column_arg = PermArg::new_multi(
+            TableId::ProcessorTable,
+            vec![from_column, 0],
+            TableId::RamTable,
+            vec![to_column, 1],
+            XFieldElement::random_elements(2, &mut rand::thread_rng()),
+        );
+        for perm_arg in [single_column_arg, multi_column_arg] {
+            let quotient = perm_arg.terminal_quotient(&ext_codeword_tables, &fri_domain, omicron);
+            let lhs_codeword = perm_arg.lhs_codeword(&ext_codeword_tables);
+            assert_eq!(
+                &lhs_codeword[5..21],
+                perm_arg
+                    .lhs_codeword_chunk(&ext_codeword_tables, 5..21)
+                    .as_ref()
+            );
+            for chunk_size in [1, 5, 16, 64, 100] {
+                let chunks = perm_arg
+                    .terminal_quotient_chunks(
+                        &ext_codeword_tables,
+                        &fri_domain,
+                        omicron,
+                        chunk_size,
+                    )
+                    .collect_vec();
+                assert_eq!(fri_domain_length.div_ceil(chunk_size), chunks.len());
+                assert!(chunks.iter().all(|chunk| chunk.len() <= chunk_size));
+                assert_eq!(quotient, chunks.concat());
+            }
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::polynomial::Polynomial;
 use twenty_first::shared_math::traits::FiniteField;
@@ -21,6 +22,41 @@ where
         polynomial.fast_coset_evaluate(&self.offset, self.omega, self.length)
     }
 
+    /// Evaluates every one of the `polynomials` on the FRI domain. The result is the same as
+    /// calling `evaluate` on each polynomial, but the powers of `offset` and the twiddle factors,
+    /// i.e., the powers of `omega`, are computed only once and shared across all polynomials.
+    pub fn evaluate_batch(&self, polynomials: &[Polynomial<PF>]) -> Vec<Vec<PF>> {
+        assert!(
+            self.length.is_power_of_two(),
+            "FRI domain length must be a power of two, but is {}",
+            self.length
+        );
+        let offset_powers = powers(self.offset, self.length);
+        let twiddles = powers(self.omega, self.length / 2);
+        let log_2_of_n = self.length.trailing_zeros();
+
+        polynomials
+            .par_iter()
+            .map(|polynomial| {
+                let coefficients = &polynomial.coefficients;
+                assert!(
+                    coefficients.len() <= self.length,
+                    "Polynomial with {} coefficients cannot be evaluated on FRI domain of length {}",
+                    coefficients.len(),
+                    self.length
+                );
+                let mut values = vec![PF::zero(); self.length];
+                for ((value, &coefficient), &offset_power) in
+                    values.iter_mut().zip(coefficients).zip(&offset_powers)
+                {
+                    *value = coefficient * offset_power;
+                }
+                ntt_with_twiddles(&mut values, &twiddles, log_2_of_n);
+                values
+            })
+            .collect()
+    }
+
     pub fn interpolate(&self, values: &[PF]) -> Polynomial<PF> {
         Polynomial::<PF>::fast_coset_interpolate(&self.offset, self.omega, values)
     }
@@ -40,21 +76,124 @@ where
 
         res
     }
+
+    /// Whether `value` is an element of the FRI domain. Since `omega` generates the subgroup of
+    /// order `length`, `value` is in the coset `offset · ⟨omega⟩` if and only if
+    /// `(value / offset)^length` is one.
+    pub fn contains(&self, value: &PF) -> bool {
+        let length = u32::try_from(self.length).expect("FRI domain length must fit into a u32");
+        (*value / self.offset).mod_pow_u32(length) == PF::one()
+    }
+
+    /// The index that a query at `initial_index` of the FRI domain corresponds to in the codeword
+    /// of every FRI round. Each folding round halves the codeword, mapping index `i` of a codeword
+    /// of length `n` to index `i mod n/2`. The returned vector has `num_rounds + 1` entries: the
+    /// `initial_index` itself, followed by the index in each folded codeword.
+    pub fn query_index_path(&self, initial_index: usize, num_rounds: usize) -> Vec<usize> {
+        assert!(
+            initial_index < self.length,
+            "Index {initial_index} is out of bounds for FRI domain of length {}",
+            self.length
+        );
+        assert!(
+            num_rounds < usize::BITS as usize && self.length >> num_rounds > 0,
+            "Cannot fold FRI domain of length {} for {num_rounds} rounds",
+            self.length
+        );
+
+        let mut index = initial_index;
+        let mut codeword_length = self.length;
+        let mut path = Vec::with_capacity(num_rounds + 1);
+        path.push(index);
+        for _ in 0..num_rounds {
+            codeword_length /= 2;
+            index %= codeword_length;
+            path.push(index);
+        }
+        path
+    }
 }
 
-pub fn lift_domain(domain: &FriDomain<BFieldElement>) -> FriDomain<XFieldElement> {
-    FriDomain {
-        offset: domain.offset.lift(),
-        omega: domain.omega.lift(),
-        length: domain.length,
+/// The first `count` powers of `base`, starting with `base^0`.
+fn powers<PF: FiniteField>(base: PF, count: usize) -> Vec<PF> {
+    let mut powers = Vec::with_capacity(count);
+    let mut acc = PF::one();
+    for _ in 0..count {
+        powers.push(acc);
+        acc *= base;
+    }
+    powers
+}
+
+/// In-place radix-2 NTT of `x`, like `ntt`, but using the precomputed `twiddles`, i.e., the first
+/// `x.len() / 2` powers of the primitive root of unity of order `x.len()`.
+fn ntt_with_twiddles<PF: FiniteField>(x: &mut [PF], twiddles: &[PF], log_2_of_n: u32) {
+    let n = x.len();
+    debug_assert_eq!(n, 1 << log_2_of_n);
+    debug_assert_eq!(n / 2, twiddles.len());
+    if n == 1 {
+        return;
+    }
+
+    for k in 0..n {
+        let rk = k.reverse_bits() >> (usize::BITS - log_2_of_n);
+        if k < rk {
+            x.swap(k, rk);
+        }
+    }
+
+    let mut m = 1;
+    while m < n {
+        let stride = n / (2 * m);
+        for k in (0..n).step_by(2 * m) {
+            for j in 0..m {
+                let t = x[k + j + m] * twiddles[j * stride];
+                let u = x[k + j];
+                x[k + j] = u + t;
+                x[k + j + m] = u - t;
+            }
+        }
+        m *= 2;
     }
 }
 
+impl FriDomain<BFieldElement> {
+    /// The same domain, embedded into the extension field. Evaluating a lifted polynomial on the
+    /// lifted domain gives the lifted evaluations on this domain.
+    pub fn lift(&self) -> FriDomain<XFieldElement> {
+        FriDomain {
+            offset: self.offset.lift(),
+            omega: self.omega.lift(),
+            length: self.length,
+        }
+    }
+}
+
+/// See `FriDomain::lift`.
+pub fn lift_domain(domain: &FriDomain<BFieldElement>) -> FriDomain<XFieldElement> {
+    domain.lift()
+}
+
+/// The smallest power-of-two expansion factor for which `num_queries` colinearity checks reach
+/// `target_security_bits` of soundness. Uses the standard FRI bound, where each query
+/// contributes `log2(expansion_factor)` bits of security, i.e., the number of security bits is
+/// `num_queries · log2(expansion_factor)`.
+pub fn min_expansion_factor(num_queries: usize, target_security_bits: usize) -> usize {
+    assert!(num_queries > 0, "At least one query is required");
+    let log_expansion_factor = target_security_bits.div_ceil(num_queries);
+    assert!(
+        log_expansion_factor < usize::BITS as usize,
+        "Expansion factor 2^{log_expansion_factor} does not fit into a usize"
+    );
+    1 << log_expansion_factor
+}
+
 #[cfg(test)]
 mod fri_domain_tests {
     use super::*;
+    use itertools::Itertools;
     use twenty_first::shared_math::b_field_element::BFieldElement;
-    use twenty_first::shared_math::traits::PrimitiveRootOfUnity;
+    use twenty_first::shared_math::traits::{GetRandomElements, PrimitiveRootOfUnity};
     use twenty_first::shared_math::x_field_element::XFieldElement;
 
     #[test]
@@ -117,4 +256,140 @@ mod fri_domain_tests {
             assert_eq!(xpol, x_interpolant);
         }
     }
+
+    #[test]
+    fn lifted_domain_evaluates_like_base_field_domain_test() {
+        let mut rng = rand::thread_rng();
+        let length = 32;
+        let b_domain = FriDomain {
+            offset: BFieldElement::generator(),
+            omega: BFieldElement::primitive_root_of_unity(length as u64).unwrap(),
+            length,
+        };
+        let x_domain = b_domain.lift();
+        assert_eq!(b_domain.offset.lift(), x_domain.offset);
+        assert_eq!(b_domain.omega.lift(), x_domain.omega);
+        assert_eq!(b_domain.length, x_domain.length);
+
+        let b_polynomial = Polynomial::new(BFieldElement::random_elements(length, &mut rng));
+        let x_polynomial = Polynomial::new(
+            b_polynomial
+                .coefficients
+                .iter()
+                .map(|coefficient| coefficient.lift())
+                .collect(),
+        );
+        let lifted_b_values = b_domain
+            .evaluate(&b_polynomial)
+            .into_iter()
+            .map(|value| value.lift())
+            .collect_vec();
+        assert_eq!(lifted_b_values, x_domain.evaluate(&x_polynomial));
+        for i in 0..length as u32 {
+            assert_eq!(b_domain.domain_value(i).lift(), x_domain.domain_value(i));
+        }
+    }
+
+    #[test]
+    fn evaluate_batch_test() {
+        let mut rng = rand::thread_rng();
+        for length in [2, 8, 64] {
+            let b_domain = FriDomain {
+                offset: BFieldElement::generator(),
+                omega: BFieldElement::primitive_root_of_unity(length as u64).unwrap(),
+                length,
+            };
+            let b_polynomials = (0..=length)
+                .map(|num_coefficients| {
+                    Polynomial::new(BFieldElement::random_elements(num_coefficients, &mut rng))
+                })
+                .collect_vec();
+            let b_codewords = b_domain.evaluate_batch(&b_polynomials);
+            assert_eq!(b_polynomials.len(), b_codewords.len());
+            for (polynomial, codeword) in b_polynomials.iter().zip_eq(b_codewords.iter()) {
+                assert_eq!(&b_domain.evaluate(polynomial), codeword);
+            }
+
+            let x_domain = lift_domain(&b_domain);
+            let x_polynomials = (0..=length)
+                .map(|num_coefficients| {
+                    Polynomial::new(XFieldElement::random_elements(num_coefficients, &mut rng))
+                })
+                .collect_vec();
+            let x_codewords = x_domain.evaluate_batch(&x_polynomials);
+            for (polynomial, codeword) in x_polynomials.iter().zip_eq(x_codewords.iter()) {
+                assert_eq!(&x_domain.evaluate(polynomial), codeword);
+            }
+        }
+    }
+
+    #[test]
+    fn min_expansion_factor_test() {
+        assert_eq!(1, min_expansion_factor(10, 0));
+        assert_eq!(4, min_expansion_factor(80, 160));
+        assert_eq!(8, min_expansion_factor(80, 161));
+        assert_eq!(8, min_expansion_factor(40, 120));
+        assert_eq!(2, min_expansion_factor(160, 160));
+
+        for num_queries in 4..50 {
+            for target_security_bits in 0..200 {
+                let expansion_factor = min_expansion_factor(num_queries, target_security_bits);
+                let log_expansion_factor = expansion_factor.trailing_zeros() as usize;
+                assert!(num_queries * log_expansion_factor >= target_security_bits);
+                if log_expansion_factor > 0 {
+                    assert!(num_queries * (log_expansion_factor - 1) < target_security_bits);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn contains_test() {
+        for length in [1, 2, 8, 32] {
+            let domain = FriDomain {
+                offset: BFieldElement::generator(),
+                omega: BFieldElement::primitive_root_of_unity(length as u64).unwrap(),
+                length,
+            };
+            let domain_values = domain.domain_values();
+
+            let candidates = (0..200)
+                .map(BFieldElement::new)
+                .chain(domain_values.iter().map(|&value| value * domain.omega))
+                .chain(domain_values.iter().map(|&value| -value))
+                .chain(
+                    domain_values
+                        .iter()
+                        .map(|&value| value + BFieldElement::new(1)),
+                );
+            for candidate in candidates {
+                assert_eq!(
+                    domain_values.contains(&candidate),
+                    domain.contains(&candidate),
+                    "Membership of {candidate} in FRI domain of length {length} is wrong"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn query_index_path_test() {
+        let length = 32;
+        let domain = FriDomain {
+            offset: BFieldElement::generator(),
+            omega: BFieldElement::primitive_root_of_unity(length as u64).unwrap(),
+            length,
+        };
+
+        assert_eq!(vec![29, 13, 5, 1, 1, 0], domain.query_index_path(29, 5));
+        assert_eq!(vec![7, 7, 7, 3], domain.query_index_path(7, 3));
+        assert_eq!(vec![17], domain.query_index_path(17, 0));
+
+        for initial_index in 0..length {
+            let path = domain.query_index_path(initial_index, 4);
+            for (round, &index) in path.iter().enumerate() {
+                assert_eq!(initial_index % (length >> round), index);
+            }
+        }
+    }
 }
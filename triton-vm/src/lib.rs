@@ -10,4 +10,5 @@ pub mod state;
 pub mod stdio;
 pub mod table;
 pub mod triton_xfri;
+pub mod util;
 pub mod vm;
@@ -1,14 +1,135 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+
 use itertools::Itertools;
+use num_traits::{One, Zero};
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::x_field_element::XFieldElement;
 use twenty_first::util_types::merkle_tree::PartialAuthenticationPath;
-use twenty_first::util_types::proof_stream_typed::ProofStreamError;
-use twenty_first::util_types::simple_hasher::{Hashable, Hasher};
+use twenty_first::util_types::simple_hasher::{Hashable, Hasher, ToVec};
+
+use crate::stark::ProofParams;
 
 type FriProof<Digest> = Vec<(PartialAuthenticationPath<Digest>, XFieldElement)>;
 type AuthenticationStructure<Digest> = Vec<PartialAuthenticationPath<Digest>>;
 
+/// The number of `BFieldElement`s making up one `XFieldElement`.
+pub const EXTENSION_DEGREE: usize = 3;
+
+/// The largest valid padded height. The omicron domain is a subgroup of the base field's
+/// multiplicative group, which has no subgroup of power-of-two order larger than `2^32`.
+pub const MAX_PADDED_HEIGHT: u64 = 1 << 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofItemError {
+    UnknownTag(u8),
+    InvalidTag(BFieldElement),
+    UnexpectedVariant {
+        expected: &'static str,
+        got: &'static str,
+    },
+    UnexpectedNumberOfElements {
+        expected: usize,
+        got: usize,
+    },
+    MissingElements {
+        expected: usize,
+        available: usize,
+    },
+    TrailingElements(usize),
+    LengthNotMultipleOfExtensionDegree(usize),
+    InvalidLength(BFieldElement),
+    InvalidOptionMarker(BFieldElement),
+    InvalidDigestLength(usize),
+    RaggedRow {
+        row_index: usize,
+        expected_width: usize,
+        actual_width: usize,
+    },
+    BadRootLength {
+        expected: usize,
+        got: usize,
+    },
+    PaddedHeightTooLarge(u64),
+    PaddedHeightNotPowerOfTwo(u64),
+    NonCanonicalElement(u64),
+    FieldModulusHalfTooLarge(u64),
+    ProofParamTooLarge(u64),
+    Io(std::io::ErrorKind),
+}
+
+impl Error for ProofItemError {}
+
+impl Display for ProofItemError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use ProofItemError::*;
+        match self {
+            UnknownTag(tag) => write!(f, "unknown proof item tag {tag}"),
+            InvalidTag(tag) => write!(f, "{tag} is not a valid proof item tag"),
+            UnexpectedVariant { expected, got } => write!(f, "expected {expected}, but got {got}"),
+            UnexpectedNumberOfElements { expected, got } => {
+                write!(f, "expected {expected} elements, but got {got}")
+            }
+            MissingElements {
+                expected,
+                available,
+            } => write!(
+                f,
+                "expected {expected} more elements, but only {available} are available"
+            ),
+            TrailingElements(num) => write!(f, "{num} elements remain after decoding"),
+            LengthNotMultipleOfExtensionDegree(len) => write!(
+                f,
+                "{len} elements cannot be grouped into extension field elements"
+            ),
+            InvalidLength(length) => write!(f, "{length} is not a valid length prefix"),
+            InvalidOptionMarker(marker) => {
+                write!(f, "{marker} is neither 0 (None) nor 1 (Some)")
+            }
+            InvalidDigestLength(len) => write!(f, "{len} elements do not form a digest"),
+            RaggedRow {
+                row_index,
+                expected_width,
+                actual_width,
+            } => write!(
+                f,
+                "row {row_index} has width {actual_width}, but expected width {expected_width}"
+            ),
+            BadRootLength { expected, got } => write!(
+                f,
+                "merkle root has {got} elements, but a digest has {expected} elements"
+            ),
+            PaddedHeightTooLarge(height) => write!(
+                f,
+                "padded height {height} exceeds the maximum of {MAX_PADDED_HEIGHT}"
+            ),
+            PaddedHeightNotPowerOfTwo(height) => {
+                write!(f, "padded height {height} is not a power of two")
+            }
+            NonCanonicalElement(value) => {
+                write!(
+                    f,
+                    "{value} is not the canonical representation of a field element"
+                )
+            }
+            FieldModulusHalfTooLarge(half) => {
+                write!(f, "{half} is not a valid 32-bit half of the field modulus")
+            }
+            ProofParamTooLarge(value) => {
+                write!(f, "proof parameter {value} does not fit into a usize")
+            }
+            Io(kind) => write!(f, "I/O error while reading proof item: {kind}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
 #[allow(clippy::large_enum_variant)]
 pub enum ProofItem<H: Hasher>
 where
@@ -21,141 +142,566 @@ where
     TransposedBaseElements(Vec<BFieldElement>),
     TransposedExtensionElements(Vec<XFieldElement>),
     AuthenticationPath(Vec<H::Digest>),
-    // FIXME: Redundancy.
-    RevealedCombinationElement(XFieldElement),
     RevealedCombinationElements(Vec<XFieldElement>),
     FriCodeword(Vec<XFieldElement>),
     FriProof(FriProof<H::Digest>),
     PaddedHeight(BFieldElement),
+    /// The digest of a claim. Proving several claims in one proof stream is not supported: the
+    /// `ProofStream` derives every challenge from its entire transcript, so the challenges of a
+    /// claim would depend on all claims before it.
+    Claim(Vec<BFieldElement>),
+    ProofParams(ProofParams),
+    /// Authentication paths for revealed leaves together with those leaves, so the two cannot get
+    /// out of sync.
+    FriResponse {
+        paths: AuthenticationStructure<H::Digest>,
+        leaves: Vec<XFieldElement>,
+    },
+    /// The encoded instructions of the program the proof is about. Binds the Fiat-Shamir
+    /// challenges to the program, such that a proof cannot be replayed for a different program.
+    Program(Vec<BFieldElement>),
+    /// The public input of the program. Together with `Program` and `PublicOutput`, the verifier
+    /// can reconstruct the claim `(program, input) -> output` from the proof stream alone.
+    PublicInput(Vec<BFieldElement>),
+    /// The public output of the program, see `PublicInput`.
+    PublicOutput(Vec<BFieldElement>),
+    /// A FRI folding challenge as sampled by the prover. Only exists with the `debug-challenge`
+    /// feature, where the verifier compares it against its own challenge to locate
+    /// prover/verifier desyncs. Does not contribute to the transcript, so Fiat-Shamir is the same
+    /// with and without it. Must remain the last variant, such that the serialization of all other
+    /// variants does not depend on the enabled features.
+    #[cfg(feature = "debug-challenge")]
+    DebugChallenge(XFieldElement),
 }
 
 impl<H: Hasher> ProofItem<H>
 where
     BFieldElement: Hashable<H::T>,
 {
+    /// Identifies the variant of the proof item. See `try_from_tagged` for the inverse.
+    pub fn tag(&self) -> u8 {
+        match self {
+            Self::CompressedAuthenticationPaths(_) => 0,
+            Self::TransposedBaseElementVectors(_) => 1,
+            Self::TransposedExtensionElementVectors(_) => 2,
+            Self::MerkleRoot(_) => 3,
+            Self::TransposedBaseElements(_) => 4,
+            Self::TransposedExtensionElements(_) => 5,
+            Self::AuthenticationPath(_) => 6,
+            Self::RevealedCombinationElements(_) => 7,
+            Self::FriCodeword(_) => 8,
+            Self::FriProof(_) => 9,
+            Self::PaddedHeight(_) => 10,
+            #[cfg(feature = "debug-challenge")]
+            Self::DebugChallenge(_) => 11,
+            Self::Claim(_) => 12,
+            Self::ProofParams(_) => 13,
+            Self::FriResponse { .. } => 14,
+            Self::Program(_) => 15,
+            Self::PublicInput(_) => 16,
+            Self::PublicOutput(_) => 17,
+        }
+    }
+
+    /// A human-readable name of the proof item's variant, used in error messages.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Self::CompressedAuthenticationPaths(_) => "compressed authentication paths",
+            Self::TransposedBaseElementVectors(_) => "transposed base element vectors",
+            Self::TransposedExtensionElementVectors(_) => "transposed extension element vectors",
+            Self::MerkleRoot(_) => "merkle root",
+            Self::TransposedBaseElements(_) => "transposed base elements",
+            Self::TransposedExtensionElements(_) => "transposed extension elements",
+            Self::AuthenticationPath(_) => "authentication path",
+            Self::RevealedCombinationElements(_) => "revealed combination elements",
+            Self::FriCodeword(_) => "FRI codeword",
+            Self::FriProof(_) => "FRI proof",
+            Self::PaddedHeight(_) => "padded table height",
+            #[cfg(feature = "debug-challenge")]
+            Self::DebugChallenge(_) => "debug challenge",
+            Self::Claim(_) => "claim",
+            Self::ProofParams(_) => "proof parameters",
+            Self::FriResponse { .. } => "FRI response",
+            Self::Program(_) => "program",
+            Self::PublicInput(_) => "public input",
+            Self::PublicOutput(_) => "public output",
+        }
+    }
+
+    fn unexpected_variant(&self, expected: &'static str) -> ProofItemError {
+        ProofItemError::UnexpectedVariant {
+            expected,
+            got: self.variant_name(),
+        }
+    }
+
     pub fn as_compressed_authentication_paths(
         &self,
-    ) -> Result<AuthenticationStructure<H::Digest>, Box<dyn std::error::Error>> {
+    ) -> Result<AuthenticationStructure<H::Digest>, ProofItemError> {
+        self.as_compressed_authentication_paths_ref()
+            .map(<[_]>::to_vec)
+    }
+
+    /// Like `as_compressed_authentication_paths`, but borrows instead of cloning.
+    pub fn as_compressed_authentication_paths_ref(
+        &self,
+    ) -> Result<&[PartialAuthenticationPath<H::Digest>], ProofItemError> {
         match self {
-            Self::CompressedAuthenticationPaths(caps) => Ok(caps.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected compressed authentication paths, but got something else",
-            )),
+            Self::CompressedAuthenticationPaths(caps) => Ok(caps),
+            _ => Err(self.unexpected_variant("compressed authentication paths")),
         }
     }
 
     pub fn as_transposed_base_element_vectors(
         &self,
-    ) -> Result<Vec<Vec<BFieldElement>>, Box<dyn std::error::Error>> {
+    ) -> Result<Vec<Vec<BFieldElement>>, ProofItemError> {
+        self.as_transposed_base_element_vectors_ref()
+            .map(<[_]>::to_vec)
+    }
+
+    /// Like `as_transposed_base_element_vectors`, but borrows instead of cloning.
+    pub fn as_transposed_base_element_vectors_ref(
+        &self,
+    ) -> Result<&[Vec<BFieldElement>], ProofItemError> {
         match self {
-            Self::TransposedBaseElementVectors(bss) => Ok(bss.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected transposed base element vectors, but got something else",
-            )),
+            Self::TransposedBaseElementVectors(bss) => Ok(bss),
+            _ => Err(self.unexpected_variant("transposed base element vectors")),
         }
     }
 
     pub fn as_transposed_extension_element_vectors(
         &self,
-    ) -> Result<Vec<Vec<XFieldElement>>, Box<dyn std::error::Error>> {
+    ) -> Result<Vec<Vec<XFieldElement>>, ProofItemError> {
+        self.as_transposed_extension_element_vectors_ref()
+            .map(<[_]>::to_vec)
+    }
+
+    /// Like `as_transposed_extension_element_vectors`, but borrows instead of cloning.
+    pub fn as_transposed_extension_element_vectors_ref(
+        &self,
+    ) -> Result<&[Vec<XFieldElement>], ProofItemError> {
         match self {
-            Self::TransposedExtensionElementVectors(xss) => Ok(xss.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected transposed extension element vectors, but got something else",
-            )),
+            Self::TransposedExtensionElementVectors(xss) => Ok(xss),
+            _ => Err(self.unexpected_variant("transposed extension element vectors")),
         }
     }
 
-    pub fn as_merkle_root(&self) -> Result<H::Digest, Box<dyn std::error::Error>> {
+    /// Like `as_transposed_base_element_vectors`, but additionally requires every vector to
+    /// have length `width`, i.e., to be a complete row of the table.
+    pub fn as_transposed_base_element_vectors_with_width(
+        &self,
+        width: usize,
+    ) -> Result<Vec<Vec<BFieldElement>>, ProofItemError> {
+        let bss = self.as_transposed_base_element_vectors()?;
+        check_row_widths(&bss, width)?;
+        Ok(bss)
+    }
+
+    /// Like `as_transposed_extension_element_vectors`, but additionally requires every vector to
+    /// have length `width`, i.e., to be a complete row of the table.
+    pub fn as_transposed_extension_element_vectors_with_width(
+        &self,
+        width: usize,
+    ) -> Result<Vec<Vec<XFieldElement>>, ProofItemError> {
+        let xss = self.as_transposed_extension_element_vectors()?;
+        check_row_widths(&xss, width)?;
+        Ok(xss)
+    }
+
+    pub fn as_merkle_root(&self) -> Result<H::Digest, ProofItemError> {
         match self {
             Self::MerkleRoot(bs) => Ok(bs.clone()),
-            _ => Err(ProofStreamError::boxed(
-                "expected merkle root, but got something else",
-            )),
+            _ => Err(self.unexpected_variant("merkle root")),
         }
     }
 
-    pub fn as_transposed_base_elements(
-        &self,
-    ) -> Result<Vec<BFieldElement>, Box<dyn std::error::Error>> {
+    pub fn as_transposed_base_elements(&self) -> Result<Vec<BFieldElement>, ProofItemError> {
         match self {
             Self::TransposedBaseElements(bs) => Ok(bs.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected tranposed base elements, but got something else",
-            )),
+            _ => Err(self.unexpected_variant("transposed base elements")),
         }
     }
 
-    pub fn as_transposed_extension_elements(
-        &self,
-    ) -> Result<Vec<XFieldElement>, Box<dyn std::error::Error>> {
+    pub fn as_transposed_extension_elements(&self) -> Result<Vec<XFieldElement>, ProofItemError> {
         match self {
             Self::TransposedExtensionElements(xs) => Ok(xs.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected tranposed extension elements, but got something else",
-            )),
+            _ => Err(self.unexpected_variant("transposed extension elements")),
         }
     }
 
-    pub fn as_authentication_path(&self) -> Result<Vec<H::Digest>, Box<dyn std::error::Error>> {
+    pub fn as_authentication_path(&self) -> Result<Vec<H::Digest>, ProofItemError> {
         match self {
             Self::AuthenticationPath(bss) => Ok(bss.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected authentication path, but got something else",
-            )),
+            _ => Err(self.unexpected_variant("authentication path")),
         }
     }
 
-    pub fn as_revealed_combination_element(
-        &self,
-    ) -> Result<XFieldElement, Box<dyn std::error::Error>> {
+    /// Like `as_authentication_path`, but borrows instead of cloning.
+    pub fn as_authentication_path_ref(&self) -> Result<&[H::Digest], ProofItemError> {
         match self {
-            Self::RevealedCombinationElement(x) => Ok(x.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected revealed combination element, but got something else",
-            )),
+            Self::AuthenticationPath(bss) => Ok(bss),
+            _ => Err(self.unexpected_variant("authentication path")),
         }
     }
 
-    pub fn as_revealed_combination_elements(
-        &self,
-    ) -> Result<Vec<XFieldElement>, Box<dyn std::error::Error>> {
+    /// A `RevealedCombinationElements` holding only `x`.
+    pub fn revealed_combination_element(x: XFieldElement) -> Self {
+        Self::RevealedCombinationElements(vec![x])
+    }
+
+    /// The sole element of a `RevealedCombinationElements`. Fails if there is not exactly one.
+    pub fn as_revealed_combination_element(&self) -> Result<XFieldElement, ProofItemError> {
+        match self.as_revealed_combination_elements_ref()? {
+            &[x] => Ok(x),
+            xs => Err(ProofItemError::UnexpectedNumberOfElements {
+                expected: 1,
+                got: xs.len(),
+            }),
+        }
+    }
+
+    pub fn as_revealed_combination_elements(&self) -> Result<Vec<XFieldElement>, ProofItemError> {
+        self.as_revealed_combination_elements_ref()
+            .map(<[_]>::to_vec)
+    }
+
+    /// Like `as_revealed_combination_elements`, but borrows instead of cloning.
+    pub fn as_revealed_combination_elements_ref(&self) -> Result<&[XFieldElement], ProofItemError> {
         match self {
-            Self::RevealedCombinationElements(xs) => Ok(xs.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected revealed combination elements, but got something else",
-            )),
+            Self::RevealedCombinationElements(xs) => Ok(xs),
+            _ => Err(self.unexpected_variant("revealed combination elements")),
         }
     }
 
-    pub fn as_fri_codeword(&self) -> Result<Vec<XFieldElement>, Box<dyn std::error::Error>> {
+    pub fn as_fri_codeword(&self) -> Result<Vec<XFieldElement>, ProofItemError> {
+        self.as_fri_codeword_ref().map(<[_]>::to_vec)
+    }
+
+    /// Like `as_fri_codeword`, but borrows instead of cloning.
+    pub fn as_fri_codeword_ref(&self) -> Result<&[XFieldElement], ProofItemError> {
         match self {
-            Self::FriCodeword(xs) => Ok(xs.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected FRI codeword, but got something else",
-            )),
+            Self::FriCodeword(xs) => Ok(xs),
+            _ => Err(self.unexpected_variant("FRI codeword")),
         }
     }
 
-    pub fn as_fri_proof(&self) -> Result<FriProof<H::Digest>, Box<dyn std::error::Error>> {
+    pub fn as_fri_proof(&self) -> Result<FriProof<H::Digest>, ProofItemError> {
         match self {
             Self::FriProof(fri_proof) => Ok(fri_proof.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected FRI proof, but got something else",
-            )),
+            _ => Err(self.unexpected_variant("FRI proof")),
         }
     }
 
-    pub fn as_padded_heights(&self) -> Result<BFieldElement, Box<dyn std::error::Error>> {
+    pub fn as_padded_heights(&self) -> Result<BFieldElement, ProofItemError> {
         match self {
             Self::PaddedHeight(padded_height) => Ok(padded_height.to_owned()),
-            _ => Err(ProofStreamError::boxed(
-                "expected padded table height, but got something else",
-            )),
+            _ => Err(self.unexpected_variant("padded table height")),
+        }
+    }
+
+    /// The padded height as a `usize`, checked to be a valid padded height. Since the padded
+    /// height determines the size of the verifier's buffers, it must not be trusted blindly.
+    pub fn as_padded_height_usize(&self) -> Result<usize, ProofItemError> {
+        let padded_height = self.as_padded_heights()?.value();
+        if padded_height > MAX_PADDED_HEIGHT {
+            return Err(ProofItemError::PaddedHeightTooLarge(padded_height));
+        }
+        if !padded_height.is_power_of_two() {
+            return Err(ProofItemError::PaddedHeightNotPowerOfTwo(padded_height));
+        }
+        usize::try_from(padded_height)
+            .map_err(|_| ProofItemError::PaddedHeightTooLarge(padded_height))
+    }
+
+    pub fn as_proof_params(&self) -> Result<ProofParams, ProofItemError> {
+        match self {
+            Self::ProofParams(params) => Ok(params.to_owned()),
+            _ => Err(self.unexpected_variant("proof parameters")),
+        }
+    }
+
+    pub fn as_claim(&self) -> Result<Vec<BFieldElement>, ProofItemError> {
+        match self {
+            Self::Claim(claim_digest) => Ok(claim_digest.to_owned()),
+            _ => Err(self.unexpected_variant("claim")),
+        }
+    }
+
+    pub fn as_program(&self) -> Result<Vec<BFieldElement>, ProofItemError> {
+        match self {
+            Self::Program(program) => Ok(program.to_owned()),
+            _ => Err(self.unexpected_variant("program")),
+        }
+    }
+
+    pub fn as_public_input(&self) -> Result<Vec<BFieldElement>, ProofItemError> {
+        match self {
+            Self::PublicInput(input) => Ok(input.to_owned()),
+            _ => Err(self.unexpected_variant("public input")),
+        }
+    }
+
+    pub fn as_public_output(&self) -> Result<Vec<BFieldElement>, ProofItemError> {
+        match self {
+            Self::PublicOutput(output) => Ok(output.to_owned()),
+            _ => Err(self.unexpected_variant("public output")),
+        }
+    }
+
+    pub fn as_fri_response(
+        &self,
+    ) -> Result<(AuthenticationStructure<H::Digest>, Vec<XFieldElement>), ProofItemError> {
+        match self {
+            Self::FriResponse { paths, leaves } => Ok((paths.to_owned(), leaves.to_owned())),
+            _ => Err(self.unexpected_variant("FRI response")),
+        }
+    }
+
+    #[cfg(feature = "debug-challenge")]
+    pub fn as_debug_challenge(&self) -> Result<XFieldElement, ProofItemError> {
+        match self {
+            Self::DebugChallenge(challenge) => Ok(challenge.to_owned()),
+            _ => Err(self.unexpected_variant("debug challenge")),
         }
     }
 }
 
+impl<H> ProofItem<H>
+where
+    H: Hasher<T = BFieldElement>,
+    H::Digest: TryFrom<Vec<BFieldElement>>,
+    BFieldElement: Hashable<H::T>,
+{
+    /// A `MerkleRoot` from a digest given as a vector, e.g., by code predating fixed-length
+    /// digests. Fails if the vector is not exactly one digest long.
+    pub fn merkle_root_from_vec(bs: Vec<BFieldElement>) -> Result<Self, ProofItemError> {
+        Ok(Self::MerkleRoot(merkle_root_digest::<H>(bs)?))
+    }
+
+    /// Encode the proof item's content as `BFieldElement`s. Unlike the flattening done by
+    /// `into_iter()`, all vectors are length-prefixed. Together with the `tag`, this makes the
+    /// encoding reversible using `try_from_tagged`.
+    pub fn as_bfield_elements(&self) -> Vec<BFieldElement> {
+        match self {
+            Self::CompressedAuthenticationPaths(paths) => {
+                length_prefixed(paths, encode_partial_auth_path::<H>)
+            }
+            Self::TransposedBaseElementVectors(bss) => {
+                length_prefixed(bss, |bs| length_prefixed(bs, |&b| vec![b]))
+            }
+            Self::TransposedExtensionElementVectors(xss) => {
+                length_prefixed(xss, |xs| length_prefixed(xs, encode_xfe))
+            }
+            Self::MerkleRoot(digest) => encode_digest::<H>(digest),
+            Self::TransposedBaseElements(bs) => length_prefixed(bs, |&b| vec![b]),
+            Self::TransposedExtensionElements(xs) => length_prefixed(xs, encode_xfe),
+            Self::AuthenticationPath(digests) => length_prefixed(digests, encode_digest::<H>),
+            Self::RevealedCombinationElements(xs) => length_prefixed(xs, encode_xfe),
+            Self::FriCodeword(xs) => length_prefixed(xs, encode_xfe),
+            Self::FriProof(fri_proof) => length_prefixed(fri_proof, |(path, x)| {
+                [encode_partial_auth_path::<H>(path), encode_xfe(x)].concat()
+            }),
+            Self::PaddedHeight(padded_height) => vec![*padded_height],
+            #[cfg(feature = "debug-challenge")]
+            Self::DebugChallenge(x) => encode_xfe(x),
+            Self::Claim(claim_digest) => length_prefixed(claim_digest, |&b| vec![b]),
+            Self::Program(program) => length_prefixed(program, |&b| vec![b]),
+            Self::PublicInput(input) => length_prefixed(input, |&b| vec![b]),
+            Self::PublicOutput(output) => length_prefixed(output, |&b| vec![b]),
+            Self::ProofParams(params) => params.to_bfield_elements(),
+            Self::FriResponse { paths, leaves } => [
+                length_prefixed(paths, encode_partial_auth_path::<H>),
+                length_prefixed(leaves, encode_xfe),
+            ]
+            .concat(),
+        }
+    }
+
+    /// The number of present digests and the total number of entries, summed over all partial
+    /// authentication paths in this proof item. The ratio of the two quantifies how effective the
+    /// compression of the paths is. `None` for proof items without partial authentication paths.
+    pub fn path_density(&self) -> Option<(usize, usize)> {
+        let density = |paths: &mut dyn Iterator<Item = &PartialAuthenticationPath<H::Digest>>| {
+            paths.fold((0, 0), |(present, total), path| {
+                (
+                    present + path.0.iter().flatten().count(),
+                    total + path.0.len(),
+                )
+            })
+        };
+        match self {
+            Self::CompressedAuthenticationPaths(paths) => Some(density(&mut paths.iter())),
+            Self::FriProof(fri_proof) => Some(density(&mut fri_proof.iter().map(|(path, _)| path))),
+            Self::FriResponse { paths, .. } => Some(density(&mut paths.iter())),
+            _ => None,
+        }
+    }
+
+    /// The number of `BFieldElement`s `into_iter()` yields for this proof item, computed without
+    /// flattening it. Only the `Some` entries of partial authentication paths contribute.
+    pub fn num_bfield_elements(&self) -> usize {
+        let digest_len = |digest: &H::Digest| digest.to_sequence().len();
+        let partial_auth_path_len = |path: &PartialAuthenticationPath<H::Digest>| -> usize {
+            path.0.iter().flatten().map(digest_len).sum()
+        };
+        match self {
+            Self::CompressedAuthenticationPaths(paths) => {
+                paths.iter().map(partial_auth_path_len).sum()
+            }
+            Self::TransposedBaseElementVectors(bss) => bss.iter().map(Vec::len).sum(),
+            Self::TransposedExtensionElementVectors(xss) => {
+                EXTENSION_DEGREE * xss.iter().map(Vec::len).sum::<usize>()
+            }
+            Self::MerkleRoot(digest) => digest_len(digest),
+            Self::TransposedBaseElements(bs) => bs.len(),
+            Self::TransposedExtensionElements(xs) => EXTENSION_DEGREE * xs.len(),
+            Self::AuthenticationPath(digests) => digests.iter().map(digest_len).sum(),
+            Self::RevealedCombinationElements(xs) => EXTENSION_DEGREE * xs.len(),
+            Self::FriCodeword(xs) => EXTENSION_DEGREE * xs.len(),
+            Self::FriProof(fri_proof) => fri_proof
+                .iter()
+                .map(|(path, _)| partial_auth_path_len(path) + EXTENSION_DEGREE)
+                .sum(),
+            Self::PaddedHeight(_) => 1,
+            #[cfg(feature = "debug-challenge")]
+            Self::DebugChallenge(_) => 0,
+            Self::Claim(claim_digest) => claim_digest.len(),
+            Self::Program(program) => program.len(),
+            Self::PublicInput(input) => input.len(),
+            Self::PublicOutput(output) => output.len(),
+            Self::ProofParams(_) => ProofParams::ENCODING_LENGTH,
+            Self::FriResponse { paths, leaves } => {
+                paths.iter().map(partial_auth_path_len).sum::<usize>()
+                    + EXTENSION_DEGREE * leaves.len()
+            }
+        }
+    }
+
+    /// The size of this proof item in bytes when transmitted, see `num_bfield_elements`.
+    pub fn byte_len(&self) -> usize {
+        bfield_elements_to_bytes(self.num_bfield_elements())
+    }
+
+    /// Decode the `elements` into the proof item variant identified by `tag`. The exact inverse of
+    /// `tag()` and `as_bfield_elements()`. Fails if the `tag` is unknown or if the `elements` are
+    /// not exactly one encoded item.
+    pub fn try_from_tagged(tag: u8, elements: &[BFieldElement]) -> Result<Self, ProofItemError> {
+        let mut reader = ElementReader { elements };
+        let item = match tag {
+            0 => Self::CompressedAuthenticationPaths(
+                reader.read_vec(|r| r.read_partial_auth_path::<H>())?,
+            ),
+            1 => Self::TransposedBaseElementVectors(
+                reader.read_vec(|r| r.read_vec(ElementReader::read_bfe))?,
+            ),
+            2 => Self::TransposedExtensionElementVectors(
+                reader.read_vec(|r| r.read_vec(ElementReader::read_xfe))?,
+            ),
+            3 => Self::MerkleRoot(reader.read_merkle_root::<H>()?),
+            4 => Self::TransposedBaseElements(reader.read_vec(ElementReader::read_bfe)?),
+            5 => Self::TransposedExtensionElements(reader.read_vec(ElementReader::read_xfe)?),
+            6 => Self::AuthenticationPath(reader.read_vec(|r| r.read_digest::<H>())?),
+            7 => Self::RevealedCombinationElements(reader.read_vec(ElementReader::read_xfe)?),
+            8 => Self::FriCodeword(reader.read_vec(ElementReader::read_xfe)?),
+            9 => Self::FriProof(
+                reader.read_vec(|r| Ok((r.read_partial_auth_path::<H>()?, r.read_xfe()?)))?,
+            ),
+            10 => Self::PaddedHeight(reader.read_bfe()?),
+            #[cfg(feature = "debug-challenge")]
+            11 => Self::DebugChallenge(reader.read_xfe()?),
+            12 => Self::Claim(reader.read_vec(ElementReader::read_bfe)?),
+            13 => {
+                let bs = reader.read(ProofParams::ENCODING_LENGTH)?;
+                Self::ProofParams(ProofParams::from_bfield_elements(bs.try_into().unwrap())?)
+            }
+            14 => Self::FriResponse {
+                paths: reader.read_vec(|r| r.read_partial_auth_path::<H>())?,
+                leaves: reader.read_vec(ElementReader::read_xfe)?,
+            },
+            15 => Self::Program(reader.read_vec(ElementReader::read_bfe)?),
+            16 => Self::PublicInput(reader.read_vec(ElementReader::read_bfe)?),
+            17 => Self::PublicOutput(reader.read_vec(ElementReader::read_bfe)?),
+            _ => return Err(ProofItemError::UnknownTag(tag)),
+        };
+        reader.finish()?;
+        Ok(item)
+    }
+
+    /// A self-describing encoding of the proof item: its `tag` followed by
+    /// `as_bfield_elements()`. The inverse of `decode`.
+    pub fn encode(&self) -> Vec<BFieldElement> {
+        [
+            vec![BFieldElement::new(self.tag() as u64)],
+            self.as_bfield_elements(),
+        ]
+        .concat()
+    }
+
+    /// Decode a proof item from the output of `encode`.
+    pub fn decode(elements: &[BFieldElement]) -> Result<Self, ProofItemError> {
+        let (&tag, content) = elements
+            .split_first()
+            .ok_or(ProofItemError::MissingElements {
+                expected: 1,
+                available: 0,
+            })?;
+        let tag = u8::try_from(tag.value()).map_err(|_| ProofItemError::InvalidTag(tag))?;
+        Self::try_from_tagged(tag, content)
+    }
+
+    /// Write the proof item to `writer` as a frame: its `tag`, the number of elements of
+    /// `as_bfield_elements()`, and those elements, each as a little-endian `u64`. The inverse of
+    /// `read_item`.
+    pub fn write_item<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let content = self.as_bfield_elements();
+        write_le_bfe(writer, BFieldElement::new(self.tag() as u64))?;
+        write_le_bfe(writer, BFieldElement::new(content.len() as u64))?;
+        content.iter().try_for_each(|&b| write_le_bfe(writer, b))
+    }
+
+    /// Read exactly one frame written by `write_item` from `reader`. Consumes nothing beyond that
+    /// frame, allowing to read a proof one item at a time.
+    pub fn read_item<R: Read>(reader: &mut R) -> Result<Self, ProofItemError> {
+        let tag = read_le_bfe(reader)?;
+        let tag = u8::try_from(tag.value()).map_err(|_| ProofItemError::InvalidTag(tag))?;
+        let length = read_le_bfe(reader)?.value();
+        // The length is untrusted, so the content is not allocated up front.
+        let content = (0..length)
+            .map(|_| read_le_bfe(reader))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::try_from_tagged(tag, &content)
+    }
+}
+
+fn write_le_bfe<W: Write>(writer: &mut W, b: BFieldElement) -> std::io::Result<()> {
+    writer.write_all(&b.value().to_le_bytes())
+}
+
+fn read_le_bfe<R: Read>(reader: &mut R) -> Result<BFieldElement, ProofItemError> {
+    let mut bytes = [0u8; 8];
+    reader
+        .read_exact(&mut bytes)
+        .map_err(|err| ProofItemError::Io(err.kind()))?;
+    let value = u64::from_le_bytes(bytes);
+    if value >= BFieldElement::QUOTIENT {
+        return Err(ProofItemError::NonCanonicalElement(value));
+    }
+    Ok(BFieldElement::new(value))
+}
+
+/// Two proof items are equal if their tagged encodings are. Since `try_from_tagged` is the exact
+/// inverse of that encoding, this is equality of the items' contents.
+impl<H> PartialEq for ProofItem<H>
+where
+    H: Hasher<T = BFieldElement>,
+    H::Digest: TryFrom<Vec<BFieldElement>>,
+    BFieldElement: Hashable<H::T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.tag() == other.tag() && self.as_bfield_elements() == other.as_bfield_elements()
+    }
+}
+
 impl<H: Hasher> IntoIterator for ProofItem<H>
 where
     BFieldElement: Hashable<H::T>,
@@ -174,7 +720,6 @@ where
                 bss.iter().map(|ap| ap.to_sequence()).concat().into_iter()
             }
 
-            ProofItem::RevealedCombinationElement(x) => bs_to_ts::<H>(&xs_to_bs(&[x])).into_iter(),
             ProofItem::FriCodeword(xs) => bs_to_ts::<H>(&xs_to_bs(&xs)).into_iter(),
             ProofItem::RevealedCombinationElements(xs) => bs_to_ts::<H>(&xs_to_bs(&xs)).into_iter(),
             ProofItem::FriProof(fri_proof) => {
@@ -207,10 +752,44 @@ where
                 bs_to_ts::<H>(&xss.into_iter().map(|xs| xs_to_bs(&xs)).concat()).into_iter()
             }
             ProofItem::PaddedHeight(padded_height) => bs_to_ts::<H>(&[padded_height]).into_iter(),
+            #[cfg(feature = "debug-challenge")]
+            ProofItem::DebugChallenge(_) => vec![].into_iter(),
+            ProofItem::Claim(claim_digest) => bs_to_ts::<H>(&claim_digest).into_iter(),
+            ProofItem::Program(program) => bs_to_ts::<H>(&program).into_iter(),
+            ProofItem::PublicInput(input) => bs_to_ts::<H>(&input).into_iter(),
+            ProofItem::PublicOutput(output) => bs_to_ts::<H>(&output).into_iter(),
+            ProofItem::ProofParams(params) => {
+                bs_to_ts::<H>(&params.to_bfield_elements()).into_iter()
+            }
+            ProofItem::FriResponse { paths, leaves } => {
+                let mut ts: Vec<H::T> = vec![];
+
+                for partial_auth_path in paths.iter() {
+                    for ts_in_partial_auth_path in partial_auth_path.0.iter().flatten() {
+                        ts.append(&mut ts_in_partial_auth_path.to_sequence());
+                    }
+                }
+                ts.append(&mut bs_to_ts::<H>(&xs_to_bs(&leaves)));
+
+                ts.into_iter()
+            }
         }
     }
 }
 
+impl<H: Hasher> ProofItem<H>
+where
+    BFieldElement: Hashable<H::T>,
+    XFieldElement: Hashable<H::T>,
+{
+    /// Append the proof item's elements to the `transcript` in the same order as
+    /// `ProofStream::enqueue` does. Hashing the `transcript` reproduces the proof stream's
+    /// Fiat-Shamir digest, which allows replaying challenge derivation one item at a time.
+    pub fn absorb_into(&self, transcript: &mut Vec<H::T>) {
+        transcript.extend(self.clone());
+    }
+}
+
 impl<H: Hasher> Default for ProofItem<H>
 where
     BFieldElement: Hashable<H::T>,
@@ -220,8 +799,170 @@ where
     }
 }
 
+/// The number of bytes taking up `num_bfield_elements` many `BFieldElement`s, each of which is
+/// a `u64` in canonical representation.
+pub fn bfield_elements_to_bytes(num_bfield_elements: usize) -> usize {
+    num_bfield_elements * std::mem::size_of::<u64>()
+}
+
+/// The digest in `bs`, reporting a wrong length as a bad Merkle root.
+fn merkle_root_digest<H>(bs: Vec<BFieldElement>) -> Result<H::Digest, ProofItemError>
+where
+    H: Hasher<T = BFieldElement>,
+    H::Digest: TryFrom<Vec<BFieldElement>>,
+{
+    let got = bs.len();
+    H::Digest::try_from(bs).map_err(|_| ProofItemError::BadRootLength {
+        expected: H::new().hash_sequence(&[]).to_sequence().len(),
+        got,
+    })
+}
+
+fn check_row_widths<T>(rows: &[Vec<T>], width: usize) -> Result<(), ProofItemError> {
+    match rows.iter().position(|row| row.len() != width) {
+        Some(row_index) => Err(ProofItemError::RaggedRow {
+            row_index,
+            expected_width: width,
+            actual_width: rows[row_index].len(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Flattens the `xs` into their coefficients, `EXTENSION_DEGREE` many per element.
 fn xs_to_bs(xs: &[XFieldElement]) -> Vec<BFieldElement> {
-    xs.iter().map(|x| x.coefficients.to_vec()).concat()
+    xs.iter()
+        .flat_map(|x| {
+            // Fails to compile if `EXTENSION_DEGREE` disagrees with the number of coefficients.
+            let coefficients: [BFieldElement; EXTENSION_DEGREE] = x.coefficients;
+            coefficients
+        })
+        .collect()
+}
+
+/// The inverse of `xs_to_bs`. Fails if the number of `bs` is not a multiple of the extension
+/// degree.
+fn xs_from_bs_checked(bs: &[BFieldElement]) -> Result<Vec<XFieldElement>, ProofItemError> {
+    if !bs.len().is_multiple_of(EXTENSION_DEGREE) {
+        return Err(ProofItemError::LengthNotMultipleOfExtensionDegree(bs.len()));
+    }
+    Ok(bs
+        .chunks_exact(EXTENSION_DEGREE)
+        .map(|chunk| XFieldElement::new([chunk[0], chunk[1], chunk[2]]))
+        .collect())
+}
+
+fn length_prefixed<T>(
+    items: &[T],
+    encode: impl Fn(&T) -> Vec<BFieldElement>,
+) -> Vec<BFieldElement> {
+    let length = BFieldElement::new(items.len() as u64);
+    [vec![length], items.iter().flat_map(encode).collect()].concat()
+}
+
+fn encode_xfe(x: &XFieldElement) -> Vec<BFieldElement> {
+    xs_to_bs(&[*x])
+}
+
+fn encode_digest<H: Hasher<T = BFieldElement>>(digest: &H::Digest) -> Vec<BFieldElement> {
+    length_prefixed(&digest.to_vec(), |&b| vec![b])
+}
+
+fn encode_partial_auth_path<H: Hasher<T = BFieldElement>>(
+    path: &PartialAuthenticationPath<H::Digest>,
+) -> Vec<BFieldElement> {
+    length_prefixed(&path.0, |maybe_digest| match maybe_digest {
+        None => vec![BFieldElement::zero()],
+        Some(digest) => [vec![BFieldElement::one()], encode_digest::<H>(digest)].concat(),
+    })
+}
+
+/// Consumes `BFieldElement`s from the front of a slice while decoding proof items.
+struct ElementReader<'a> {
+    elements: &'a [BFieldElement],
+}
+
+impl<'a> ElementReader<'a> {
+    fn read(&mut self, num_elements: usize) -> Result<&'a [BFieldElement], ProofItemError> {
+        if num_elements > self.elements.len() {
+            return Err(ProofItemError::MissingElements {
+                expected: num_elements,
+                available: self.elements.len(),
+            });
+        }
+        let (read, remaining) = self.elements.split_at(num_elements);
+        self.elements = remaining;
+        Ok(read)
+    }
+
+    fn read_bfe(&mut self) -> Result<BFieldElement, ProofItemError> {
+        Ok(self.read(1)?[0])
+    }
+
+    fn read_xfe(&mut self) -> Result<XFieldElement, ProofItemError> {
+        Ok(xs_from_bs_checked(self.read(EXTENSION_DEGREE)?)?[0])
+    }
+
+    fn read_length(&mut self) -> Result<usize, ProofItemError> {
+        let length = self.read_bfe()?;
+        // Every encoded item takes up at least one element, which bounds any valid length.
+        match usize::try_from(length.value()) {
+            Ok(length) if length <= self.elements.len() => Ok(length),
+            _ => Err(ProofItemError::InvalidLength(length)),
+        }
+    }
+
+    fn read_vec<T>(
+        &mut self,
+        read_item: impl Fn(&mut Self) -> Result<T, ProofItemError>,
+    ) -> Result<Vec<T>, ProofItemError> {
+        let length = self.read_length()?;
+        (0..length).map(|_| read_item(self)).collect()
+    }
+
+    fn read_digest<H>(&mut self) -> Result<H::Digest, ProofItemError>
+    where
+        H: Hasher<T = BFieldElement>,
+        H::Digest: TryFrom<Vec<BFieldElement>>,
+    {
+        let bs = self.read_vec(Self::read_bfe)?;
+        let num_bs = bs.len();
+        H::Digest::try_from(bs).map_err(|_| ProofItemError::InvalidDigestLength(num_bs))
+    }
+
+    /// Like `read_digest`, but reports a digest of the wrong length as a bad Merkle root.
+    fn read_merkle_root<H>(&mut self) -> Result<H::Digest, ProofItemError>
+    where
+        H: Hasher<T = BFieldElement>,
+        H::Digest: TryFrom<Vec<BFieldElement>>,
+    {
+        merkle_root_digest::<H>(self.read_vec(Self::read_bfe)?)
+    }
+
+    fn read_partial_auth_path<H>(
+        &mut self,
+    ) -> Result<PartialAuthenticationPath<H::Digest>, ProofItemError>
+    where
+        H: Hasher<T = BFieldElement>,
+        H::Digest: TryFrom<Vec<BFieldElement>>,
+    {
+        let maybe_digests = self.read_vec(|r| {
+            let marker = r.read_bfe()?;
+            match marker.value() {
+                0 => Ok(None),
+                1 => Ok(Some(r.read_digest::<H>()?)),
+                _ => Err(ProofItemError::InvalidOptionMarker(marker)),
+            }
+        })?;
+        Ok(PartialAuthenticationPath(maybe_digests))
+    }
+
+    fn finish(self) -> Result<(), ProofItemError> {
+        match self.elements.len() {
+            0 => Ok(()),
+            num_remaining => Err(ProofItemError::TrailingElements(num_remaining)),
+        }
+    }
 }
 
 fn bs_to_ts<H: Hasher>(bs: &[BFieldElement]) -> Vec<H::T>
@@ -232,3 +973,681 @@ where
         .flat_map(|b| b.to_sequence())
         .collect::<Vec<H::T>>()
 }
+
+#[cfg(test)]
+mod proof_item_tests {
+    use std::io::Cursor;
+
+    use twenty_first::shared_math::rescue_prime_regular::RescuePrimeRegular;
+    use twenty_first::shared_math::traits::GetRandomElements;
+    use twenty_first::util_types::proof_stream_typed::ProofStream;
+
+    use super::*;
+
+    type Item = ProofItem<RescuePrimeRegular>;
+
+    fn digest(seed: u64) -> [BFieldElement; 5] {
+        RescuePrimeRegular::new().hash_sequence(&[BFieldElement::new(seed)])
+    }
+
+    fn random_xfes(num_elements: usize) -> Vec<XFieldElement> {
+        XFieldElement::random_elements(num_elements, &mut rand::thread_rng())
+    }
+
+    fn one_item_of_each_variant() -> Vec<Item> {
+        let partial_auth_path = PartialAuthenticationPath(vec![Some(digest(1)), None, None]);
+        let bs = vec![BFieldElement::new(7), BFieldElement::new(11)];
+        vec![
+            Item::CompressedAuthenticationPaths(vec![
+                partial_auth_path.clone(),
+                PartialAuthenticationPath(vec![]),
+            ]),
+            Item::TransposedBaseElementVectors(vec![bs.clone(), vec![], bs.clone()]),
+            Item::TransposedExtensionElementVectors(vec![random_xfes(3), random_xfes(1)]),
+            Item::MerkleRoot(digest(2)),
+            Item::TransposedBaseElements(bs),
+            Item::TransposedExtensionElements(random_xfes(4)),
+            Item::AuthenticationPath(vec![digest(3), digest(4)]),
+            Item::RevealedCombinationElements(random_xfes(5)),
+            Item::FriCodeword(random_xfes(8)),
+            Item::FriProof(vec![(partial_auth_path.clone(), random_xfes(1)[0])]),
+            Item::PaddedHeight(BFieldElement::new(1024)),
+            #[cfg(feature = "debug-challenge")]
+            Item::DebugChallenge(random_xfes(1)[0]),
+            Item::Claim(vec![BFieldElement::new(3), BFieldElement::new(5)]),
+            Item::ProofParams(ProofParams {
+                field_modulus: BFieldElement::QUOTIENT,
+                extension_degree: EXTENSION_DEGREE,
+                expansion_factor: 4,
+                num_trace_randomizers: 64,
+                num_randomizer_polynomials: 1,
+                security_level: 32,
+            }),
+            Item::FriResponse {
+                paths: vec![partial_auth_path, PartialAuthenticationPath(vec![None])],
+                leaves: random_xfes(2),
+            },
+            Item::Program(vec![BFieldElement::new(2), BFieldElement::new(1)]),
+            Item::PublicInput(vec![BFieldElement::new(5)]),
+            Item::PublicOutput(vec![BFieldElement::new(8), BFieldElement::new(13)]),
+        ]
+    }
+
+    #[test]
+    fn tagged_encoding_round_trip_test() {
+        let items = one_item_of_each_variant();
+        assert_eq!(
+            items.len(),
+            items.iter().map(|item| item.tag()).unique().count(),
+            "Every variant must have its own tag."
+        );
+
+        for item in items {
+            let elements = item.as_bfield_elements();
+            let decoded = Item::try_from_tagged(item.tag(), &elements).unwrap();
+            assert_eq!(item.tag(), decoded.tag());
+            assert_eq!(elements, decoded.as_bfield_elements());
+            assert_eq!(format!("{item:?}"), format!("{decoded:?}"));
+        }
+    }
+
+    #[test]
+    fn encode_decode_round_trip_test() {
+        for item in one_item_of_each_variant() {
+            let encoding = item.encode();
+            assert_eq!(BFieldElement::new(item.tag() as u64), encoding[0]);
+            let decoded = Item::decode(&encoding).unwrap();
+            assert_eq!(format!("{item:?}"), format!("{decoded:?}"));
+            assert_eq!(encoding, decoded.encode());
+        }
+    }
+
+    #[test]
+    fn framed_items_round_trip_over_byte_stream_test() {
+        let items = one_item_of_each_variant();
+        let mut writer = Cursor::new(vec![]);
+        for item in items.iter() {
+            item.write_item(&mut writer).unwrap();
+        }
+
+        let mut reader = Cursor::new(writer.into_inner());
+        for item in items.iter() {
+            let read_item = Item::read_item(&mut reader).unwrap();
+            assert_eq!(format!("{item:?}"), format!("{read_item:?}"));
+        }
+        assert_eq!(
+            ProofItemError::Io(std::io::ErrorKind::UnexpectedEof),
+            Item::read_item(&mut reader).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn malformed_frames_are_rejected_test() {
+        let mut bytes = vec![];
+        Item::PaddedHeight(BFieldElement::new(16))
+            .write_item(&mut bytes)
+            .unwrap();
+
+        let truncated = &bytes[..bytes.len() - 1];
+        assert_eq!(
+            ProofItemError::Io(std::io::ErrorKind::UnexpectedEof),
+            Item::read_item(&mut Cursor::new(truncated)).unwrap_err()
+        );
+
+        let mut non_canonical = bytes.clone();
+        non_canonical[16..].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(
+            ProofItemError::NonCanonicalElement(u64::MAX),
+            Item::read_item(&mut Cursor::new(non_canonical)).unwrap_err()
+        );
+
+        let mut wrong_length = bytes;
+        wrong_length[8..16].copy_from_slice(&0u64.to_le_bytes());
+        wrong_length.truncate(16);
+        assert_eq!(
+            ProofItemError::MissingElements {
+                expected: 1,
+                available: 0
+            },
+            Item::read_item(&mut Cursor::new(wrong_length)).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn byte_len_matches_flattened_length_test() {
+        for item in one_item_of_each_variant() {
+            let num_bfield_elements = item.clone().into_iter().count();
+            assert_eq!(
+                num_bfield_elements,
+                item.num_bfield_elements(),
+                "Mismatch for {}",
+                item.variant_name()
+            );
+            assert_eq!(8 * num_bfield_elements, item.byte_len());
+        }
+    }
+
+    #[test]
+    fn single_revealed_combination_element_test() {
+        let x = random_xfes(1)[0];
+        let item = Item::revealed_combination_element(x);
+        assert_eq!(x, item.as_revealed_combination_element().unwrap());
+        assert_eq!(x.coefficients.to_vec(), item.into_iter().collect_vec());
+
+        let two_elements = Item::RevealedCombinationElements(random_xfes(2));
+        assert_eq!(
+            ProofItemError::UnexpectedNumberOfElements {
+                expected: 1,
+                got: 2
+            },
+            two_elements.as_revealed_combination_element().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn path_density_test() {
+        let paths = vec![
+            PartialAuthenticationPath(vec![Some(digest(1)), None, Some(digest(2)), None]),
+            PartialAuthenticationPath(vec![None, None]),
+            PartialAuthenticationPath(vec![]),
+            PartialAuthenticationPath(vec![Some(digest(3))]),
+        ];
+        let caps = Item::CompressedAuthenticationPaths(paths.clone());
+        assert_eq!(Some((3, 7)), caps.path_density());
+
+        let fri_proof = Item::FriProof(paths.into_iter().zip(random_xfes(4)).collect());
+        assert_eq!(Some((3, 7)), fri_proof.path_density());
+
+        assert_eq!(
+            Some((0, 0)),
+            Item::CompressedAuthenticationPaths(vec![]).path_density()
+        );
+        assert_eq!(None, Item::MerkleRoot(digest(4)).path_density());
+        assert_eq!(None, Item::FriCodeword(random_xfes(2)).path_density());
+    }
+
+    #[test]
+    fn program_test() {
+        let program = vec![
+            BFieldElement::new(1),
+            BFieldElement::new(42),
+            BFieldElement::new(0),
+        ];
+        let item = Item::Program(program.clone());
+        assert_eq!(program, item.as_program().unwrap());
+
+        let decoded = Item::decode(&item.encode()).unwrap();
+        assert_eq!(program, decoded.as_program().unwrap());
+
+        // The instructions are absorbed as they are, without a length prefix.
+        assert_eq!(3, item.num_bfield_elements());
+        assert_eq!(8 * 3, item.byte_len());
+        assert_eq!(program, item.into_iter().collect_vec());
+
+        assert_eq!(
+            ProofItemError::UnexpectedVariant {
+                expected: "program",
+                got: "claim",
+            },
+            Item::Claim(program).as_program().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn public_input_and_output_test() {
+        let input = vec![BFieldElement::new(5), BFieldElement::new(7)];
+        let output = vec![BFieldElement::new(12)];
+        let input_item = Item::PublicInput(input.clone());
+        let output_item = Item::PublicOutput(output.clone());
+
+        let decoded_input = Item::decode(&input_item.encode()).unwrap();
+        let decoded_output = Item::decode(&output_item.encode()).unwrap();
+        assert_eq!(input, decoded_input.as_public_input().unwrap());
+        assert_eq!(output, decoded_output.as_public_output().unwrap());
+        assert_eq!(input, input_item.clone().into_iter().collect_vec());
+        assert_eq!(output, output_item.clone().into_iter().collect_vec());
+
+        // Input and output of the same values are distinguishable by their tag.
+        assert_ne!(
+            Item::PublicInput(output.clone()).encode(),
+            output_item.encode()
+        );
+        assert!(input_item.as_public_output().is_err());
+
+        // A different output is detected, both in the decoded item and in its transcript.
+        let mismatched_output = vec![BFieldElement::new(13)];
+        let mismatched_item = Item::PublicOutput(mismatched_output.clone());
+        let decoded_mismatch = Item::decode(&mismatched_item.encode()).unwrap();
+        assert_ne!(output, decoded_mismatch.as_public_output().unwrap());
+        assert_ne!(
+            output_item.into_iter().collect_vec(),
+            mismatched_item.into_iter().collect_vec()
+        );
+    }
+
+    #[test]
+    fn absorb_into_matches_proof_stream_test() {
+        let hasher = RescuePrimeRegular::new();
+        let mut proof_stream: ProofStream<Item, RescuePrimeRegular> = ProofStream::default();
+        let mut transcript = vec![];
+        for item in one_item_of_each_variant() {
+            proof_stream.enqueue(&item);
+            item.absorb_into(&mut transcript);
+            assert_eq!(proof_stream.transcript_length(), transcript.len());
+            assert_eq!(
+                proof_stream.prover_fiat_shamir(),
+                hasher.hash_sequence(&transcript)
+            );
+        }
+    }
+
+    #[test]
+    fn borrowing_accessors_match_owning_accessors_test() {
+        let xs = random_xfes(10);
+        let fri_codeword = Item::FriCodeword(xs.clone());
+        assert_eq!(xs, fri_codeword.as_fri_codeword().unwrap());
+        assert_eq!(xs.as_slice(), fri_codeword.as_fri_codeword_ref().unwrap());
+
+        let revealed = Item::RevealedCombinationElements(xs.clone());
+        assert_eq!(
+            revealed.as_revealed_combination_elements().unwrap(),
+            revealed.as_revealed_combination_elements_ref().unwrap()
+        );
+
+        let xss = vec![random_xfes(3), random_xfes(3)];
+        let ext_vectors = Item::TransposedExtensionElementVectors(xss.clone());
+        assert_eq!(
+            xss,
+            ext_vectors
+                .as_transposed_extension_element_vectors()
+                .unwrap()
+        );
+        assert_eq!(
+            xss.as_slice(),
+            ext_vectors
+                .as_transposed_extension_element_vectors_ref()
+                .unwrap()
+        );
+
+        let bss = vec![vec![BFieldElement::new(3), BFieldElement::new(5)]];
+        let base_vectors = Item::TransposedBaseElementVectors(bss.clone());
+        assert_eq!(
+            bss.as_slice(),
+            base_vectors
+                .as_transposed_base_element_vectors_ref()
+                .unwrap()
+        );
+
+        let paths = vec![PartialAuthenticationPath(vec![Some(digest(2)), None])];
+        let compressed = Item::CompressedAuthenticationPaths(paths.clone());
+        assert_eq!(
+            paths.as_slice(),
+            compressed.as_compressed_authentication_paths_ref().unwrap()
+        );
+
+        let path = vec![digest(3), digest(4)];
+        let authentication_path = Item::AuthenticationPath(path.clone());
+        assert_eq!(
+            path.as_slice(),
+            authentication_path.as_authentication_path_ref().unwrap()
+        );
+
+        assert!(fri_codeword.as_authentication_path_ref().is_err());
+        assert!(authentication_path.as_fri_codeword_ref().is_err());
+    }
+
+    #[test]
+    fn padded_height_usize_test() {
+        let padded_height = |height: u64| Item::PaddedHeight(BFieldElement::new(height));
+        assert_eq!(Ok(1), padded_height(1).as_padded_height_usize());
+        assert_eq!(Ok(1024), padded_height(1024).as_padded_height_usize());
+        assert_eq!(
+            Ok(MAX_PADDED_HEIGHT as usize),
+            padded_height(MAX_PADDED_HEIGHT).as_padded_height_usize()
+        );
+
+        assert_eq!(
+            Err(ProofItemError::PaddedHeightTooLarge(2 * MAX_PADDED_HEIGHT)),
+            padded_height(2 * MAX_PADDED_HEIGHT).as_padded_height_usize()
+        );
+        let largest_element = BFieldElement::QUOTIENT - 1;
+        assert_eq!(
+            Err(ProofItemError::PaddedHeightTooLarge(largest_element)),
+            padded_height(largest_element).as_padded_height_usize()
+        );
+
+        for height in [0, 3, 1000, MAX_PADDED_HEIGHT - 1] {
+            assert_eq!(
+                Err(ProofItemError::PaddedHeightNotPowerOfTwo(height)),
+                padded_height(height).as_padded_height_usize()
+            );
+        }
+
+        assert!(Item::Claim(vec![]).as_padded_height_usize().is_err());
+    }
+
+    #[test]
+    fn multi_query_fri_proof_round_trip_test() {
+        let paths = vec![
+            PartialAuthenticationPath(vec![Some(digest(1)), Some(digest(2)), Some(digest(3))]),
+            PartialAuthenticationPath(vec![None, Some(digest(4)), None]),
+            PartialAuthenticationPath(vec![None, None, None]),
+            PartialAuthenticationPath(vec![Some(digest(5)), None]),
+            PartialAuthenticationPath(vec![]),
+        ];
+        let fri_proof = paths.into_iter().zip_eq(random_xfes(5)).collect_vec();
+        let item = Item::FriProof(fri_proof.clone());
+
+        let encoding = item.encode();
+        let decoded = Item::decode(&encoding).unwrap();
+        assert_eq!(fri_proof, decoded.as_fri_proof().unwrap());
+        assert_eq!(encoding, decoded.encode());
+
+        // Dropping the last element cuts off the final leaf.
+        assert!(Item::decode(&encoding[..encoding.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn fri_response_test() {
+        let paths = vec![PartialAuthenticationPath(vec![None, Some(digest(5))])];
+        let leaves = random_xfes(3);
+        let item = Item::FriResponse {
+            paths: paths.clone(),
+            leaves: leaves.clone(),
+        };
+        assert_eq!((paths, leaves.clone()), item.as_fri_response().unwrap());
+
+        let decoded = Item::decode(&item.encode()).unwrap();
+        assert_eq!(format!("{item:?}"), format!("{decoded:?}"));
+
+        // One digest followed by three extension field elements
+        assert_eq!(5 + 3 * EXTENSION_DEGREE, item.num_bfield_elements());
+        let flattened = item.into_iter().collect_vec();
+        assert_eq!(digest(5).to_vec(), flattened[..5]);
+        assert_eq!(xs_to_bs(&leaves), flattened[5..]);
+    }
+
+    #[test]
+    fn accessing_wrong_variant_reports_actual_variant_test() {
+        let item = Item::MerkleRoot(digest(0));
+        let err = item.as_fri_codeword().unwrap_err();
+        assert_eq!(
+            ProofItemError::UnexpectedVariant {
+                expected: "FRI codeword",
+                got: "merkle root",
+            },
+            err
+        );
+        assert_eq!(
+            "expected FRI codeword, but got merkle root",
+            err.to_string()
+        );
+        assert_eq!(
+            one_item_of_each_variant().len(),
+            one_item_of_each_variant()
+                .iter()
+                .map(|item| item.variant_name())
+                .unique()
+                .count()
+        );
+    }
+
+    #[test]
+    fn every_accessor_reports_expected_and_actual_variant_test() {
+        type Accessor = fn(&Item) -> Result<(), ProofItemError>;
+        let accessors: Vec<Accessor> = vec![
+            |item| item.as_compressed_authentication_paths().map(drop),
+            |item| item.as_compressed_authentication_paths_ref().map(drop),
+            |item| item.as_transposed_base_element_vectors().map(drop),
+            |item| item.as_transposed_base_element_vectors_ref().map(drop),
+            |item| item.as_transposed_extension_element_vectors().map(drop),
+            |item| item.as_transposed_extension_element_vectors_ref().map(drop),
+            |item| item.as_merkle_root().map(drop),
+            |item| item.as_transposed_base_elements().map(drop),
+            |item| item.as_transposed_extension_elements().map(drop),
+            |item| item.as_authentication_path().map(drop),
+            |item| item.as_authentication_path_ref().map(drop),
+            |item| item.as_revealed_combination_elements().map(drop),
+            |item| item.as_revealed_combination_elements_ref().map(drop),
+            |item| item.as_fri_codeword().map(drop),
+            |item| item.as_fri_codeword_ref().map(drop),
+            |item| item.as_fri_proof().map(drop),
+            |item| item.as_padded_heights().map(drop),
+            |item| item.as_padded_height_usize().map(drop),
+            |item| item.as_proof_params().map(drop),
+            |item| item.as_claim().map(drop),
+            |item| item.as_program().map(drop),
+            |item| item.as_public_input().map(drop),
+            |item| item.as_public_output().map(drop),
+            |item| item.as_fri_response().map(drop),
+            #[cfg(feature = "debug-challenge")]
+            |item| item.as_debug_challenge().map(drop),
+        ];
+
+        let items = one_item_of_each_variant();
+        for accessor in accessors {
+            let (matching, mismatching): (Vec<_>, Vec<_>) =
+                items.iter().partition(|item| accessor(item).is_ok());
+            assert_eq!(1, matching.len());
+            let expected = matching[0].variant_name();
+
+            for item in mismatching {
+                let err = accessor(item).unwrap_err();
+                let got = item.variant_name();
+                assert_eq!(ProofItemError::UnexpectedVariant { expected, got }, err);
+                assert_eq!(
+                    format!("expected {expected}, but got {got}"),
+                    err.to_string()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn decoding_without_valid_tag_fails_test() {
+        assert_eq!(
+            ProofItemError::MissingElements {
+                expected: 1,
+                available: 0
+            },
+            Item::decode(&[]).unwrap_err()
+        );
+        let invalid_tag = BFieldElement::new(256);
+        assert_eq!(
+            ProofItemError::InvalidTag(invalid_tag),
+            Item::decode(&[invalid_tag]).unwrap_err()
+        );
+        assert_eq!(
+            ProofItemError::UnknownTag(255),
+            Item::decode(&[BFieldElement::new(255)]).unwrap_err()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_test() {
+        for item in one_item_of_each_variant() {
+            let json = serde_json::to_string(&item).unwrap();
+            let from_json: Item = serde_json::from_str(&json).unwrap();
+            assert_eq!(item, from_json);
+
+            let bytes = bincode::serialize(&item).unwrap();
+            let from_bincode: Item = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(item, from_bincode);
+        }
+    }
+
+    /// The serialization must not depend on the build profile or the `debug-challenge` feature,
+    /// such that proofs serialized by debug builds can be deserialized by release builds and vice
+    /// versa.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_variant_index_does_not_depend_on_build_configuration_test() {
+        let item = Item::PublicOutput(vec![BFieldElement::new(8)]);
+        let bytes = bincode::serialize(&item).unwrap();
+        assert_eq!(16u32.to_le_bytes(), bytes[..4]);
+        assert_eq!(item, bincode::deserialize::<Item>(&bytes).unwrap());
+    }
+
+    #[test]
+    fn malformed_tagged_encoding_is_rejected_test() {
+        let item = Item::FriCodeword(random_xfes(2));
+        let elements = item.as_bfield_elements();
+
+        assert_eq!(
+            Err(ProofItemError::UnknownTag(200)),
+            Item::try_from_tagged(200, &elements).map(|i| i.tag())
+        );
+        assert_eq!(
+            Err(ProofItemError::TrailingElements(1)),
+            Item::try_from_tagged(
+                item.tag(),
+                &[elements.clone(), vec![BFieldElement::one()]].concat()
+            )
+            .map(|i| i.tag())
+        );
+        assert_eq!(
+            Err(ProofItemError::MissingElements {
+                expected: 3,
+                available: 2
+            }),
+            Item::try_from_tagged(item.tag(), &elements[..elements.len() - 1]).map(|i| i.tag())
+        );
+
+        let mut bad_marker =
+            Item::CompressedAuthenticationPaths(vec![PartialAuthenticationPath(vec![None])])
+                .as_bfield_elements();
+        bad_marker[2] = BFieldElement::new(2);
+        assert_eq!(
+            Err(ProofItemError::InvalidOptionMarker(BFieldElement::new(2))),
+            Item::try_from_tagged(0, &bad_marker).map(|i| i.tag())
+        );
+
+        let path_with_short_digest = [1, 1, 0].map(BFieldElement::new);
+        assert_eq!(
+            Err(ProofItemError::InvalidDigestLength(1)),
+            Item::try_from_tagged(6, &path_with_short_digest).map(|i| i.tag())
+        );
+
+        let mut proof_params = [0, 1, 3, 4, 2, 1, 32].map(BFieldElement::new);
+        assert!(Item::try_from_tagged(13, &proof_params).is_ok());
+        proof_params[1] = BFieldElement::new(u32::MAX as u64 + 1);
+        assert_eq!(
+            Err(ProofItemError::FieldModulusHalfTooLarge(
+                u32::MAX as u64 + 1
+            )),
+            Item::try_from_tagged(13, &proof_params).map(|i| i.tag())
+        );
+    }
+
+    #[test]
+    fn merkle_root_of_wrong_length_is_rejected_test() {
+        let root = Item::MerkleRoot(digest(42));
+        let elements = root.as_bfield_elements();
+        assert_eq!(
+            digest(42),
+            Item::try_from_tagged(root.tag(), &elements)
+                .unwrap()
+                .as_merkle_root()
+                .unwrap()
+        );
+
+        let short_root = [2, 1, 0].map(BFieldElement::new);
+        let err = Item::try_from_tagged(root.tag(), &short_root).unwrap_err();
+        assert_eq!(
+            ProofItemError::BadRootLength {
+                expected: 5,
+                got: 2
+            },
+            err
+        );
+        assert_eq!(
+            "merkle root has 2 elements, but a digest has 5 elements",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn merkle_root_from_vec_test() {
+        let root = Item::merkle_root_from_vec(digest(42).to_vec()).unwrap();
+        assert_eq!(digest(42), root.as_merkle_root().unwrap());
+
+        let too_long_root = [digest(42).to_vec(), vec![BFieldElement::one()]].concat();
+        assert_eq!(
+            ProofItemError::BadRootLength {
+                expected: 5,
+                got: 6
+            },
+            Item::merkle_root_from_vec(too_long_root).unwrap_err()
+        );
+        assert_eq!(
+            ProofItemError::BadRootLength {
+                expected: 5,
+                got: 0
+            },
+            Item::merkle_root_from_vec(vec![]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn xs_to_bs_flattens_to_extension_degree_many_elements_test() {
+        let xs = random_xfes(10);
+        let bs = xs_to_bs(&xs);
+        assert_eq!(EXTENSION_DEGREE * xs.len(), bs.len());
+        for (x, chunk) in xs.iter().zip_eq(bs.chunks_exact(EXTENSION_DEGREE)) {
+            assert_eq!(x.coefficients.as_slice(), chunk);
+        }
+        assert!(xs_to_bs(&[]).is_empty());
+    }
+
+    #[test]
+    fn xs_from_bs_checked_is_inverse_of_xs_to_bs_test() {
+        let xs = random_xfes(10);
+        assert_eq!(xs, xs_from_bs_checked(&xs_to_bs(&xs)).unwrap());
+        assert_eq!(
+            Err(ProofItemError::LengthNotMultipleOfExtensionDegree(4)),
+            xs_from_bs_checked(&[BFieldElement::one(); 4])
+        );
+    }
+
+    #[test]
+    fn transposed_element_vectors_with_width_test() {
+        let bs = vec![BFieldElement::new(7), BFieldElement::new(11)];
+        let base_rows = Item::TransposedBaseElementVectors(vec![bs.clone(), bs.clone()]);
+        assert_eq!(
+            Ok(vec![bs.clone(), bs.clone()]),
+            base_rows.as_transposed_base_element_vectors_with_width(2)
+        );
+
+        let ragged_base_rows =
+            Item::TransposedBaseElementVectors(vec![bs.clone(), bs[..1].to_vec(), bs]);
+        assert_eq!(
+            Err(ProofItemError::RaggedRow {
+                row_index: 1,
+                expected_width: 2,
+                actual_width: 1,
+            }),
+            ragged_base_rows.as_transposed_base_element_vectors_with_width(2)
+        );
+
+        let xs = random_xfes(3);
+        let ragged_ext_rows = Item::TransposedExtensionElementVectors(vec![xs.clone(), vec![]]);
+        assert_eq!(
+            Err(ProofItemError::RaggedRow {
+                row_index: 0,
+                expected_width: 2,
+                actual_width: 3,
+            }),
+            ragged_ext_rows.as_transposed_extension_element_vectors_with_width(2)
+        );
+        assert!(ragged_ext_rows
+            .as_transposed_extension_element_vectors_with_width(3)
+            .is_err());
+
+        let ext_rows = Item::TransposedExtensionElementVectors(vec![xs.clone()]);
+        assert_eq!(
+            Ok(vec![xs]),
+            ext_rows.as_transposed_extension_element_vectors_with_width(3)
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::error::Error;
+use std::fmt::{Display, Formatter};
 
 use itertools::Itertools;
 use rayon::iter::{
@@ -23,15 +24,314 @@ use crate::cross_table_arguments::{
     CrossTableArg, EvalArg, GrandCrossTableArg, NUM_CROSS_TABLE_ARGS, NUM_PUBLIC_EVAL_ARGS,
 };
 use crate::fri_domain::FriDomain;
-use crate::proof_item::ProofItem;
+use crate::proof_item::{bfield_elements_to_bytes, ProofItem, ProofItemError, EXTENSION_DEGREE};
 use crate::table::challenges::AllChallenges;
 use crate::table::table_collection::{derive_omicron, BaseTableCollection, ExtTableCollection};
+use crate::table::table_column::ProgramBaseTableColumn;
 use crate::triton_xfri::{self, Fri};
+use crate::util::ct_eq;
 
 use super::table::base_matrix::BaseMatrices;
 
 pub type StarkHasher = RescuePrimeRegular;
-pub type StarkProofStream = ProofStream<ProofItem<StarkHasher>, StarkHasher>;
+/// The proof stream of a STARK, generic over the hasher used for Fiat-Shamir. The prover and
+/// verifier use the `StarkHasher`.
+pub type StarkProofStream<H = StarkHasher> = ProofStream<ProofItem<H>, H>;
+
+/// The size of a proof stream as it would be transmitted.
+pub trait ProofSize {
+    /// The number of bytes all enqueued proof items take up. See `ProofItem::byte_len`.
+    fn total_byte_len(&self) -> usize;
+
+    /// The number of bytes the enqueued proof items take up, broken down by kind of item. Reads
+    /// the proof stream from the start and leaves it reset for the verifier.
+    fn size_report(&mut self) -> ProofSizeReport;
+}
+
+impl ProofSize for StarkProofStream {
+    fn total_byte_len(&self) -> usize {
+        // The transcript is the concatenation of all enqueued items' flattened elements.
+        bfield_elements_to_bytes(self.transcript_length())
+    }
+
+    fn size_report(&mut self) -> ProofSizeReport {
+        let mut report = ProofSizeReport::default();
+        self.reset_for_verifier();
+        while let Ok(item) = self.dequeue() {
+            report.add_item(&item);
+        }
+        self.reset_for_verifier();
+        report
+    }
+}
+
+/// An overview of the items in a proof stream, for finding the first item on which the prover's
+/// and the verifier's transcripts diverge.
+pub trait TranscriptSummary {
+    /// The index, variant name, and number of flattened `BFieldElement`s of every enqueued proof
+    /// item, in the order of the transcript. Reads the proof stream from the start and leaves it
+    /// reset for the verifier.
+    fn transcript_summary(&mut self) -> Vec<(usize, &'static str, usize)>;
+}
+
+impl TranscriptSummary for StarkProofStream {
+    fn transcript_summary(&mut self) -> Vec<(usize, &'static str, usize)> {
+        let mut summary = vec![];
+        self.reset_for_verifier();
+        while let Ok(item) = self.dequeue() {
+            summary.push((
+                summary.len(),
+                item.variant_name(),
+                item.num_bfield_elements(),
+            ));
+        }
+        self.reset_for_verifier();
+        summary
+    }
+}
+
+/// The number of bytes spent on each kind of proof item, see `ProofSize::size_report`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProofSizeReport {
+    pub merkle_roots: usize,
+    pub authentication_paths: usize,
+    pub revealed_elements: usize,
+    pub fri_codewords: usize,
+    pub fri_proofs: usize,
+    pub fri_responses: usize,
+    pub metadata: usize,
+}
+
+impl ProofSizeReport {
+    pub fn add_item(&mut self, item: &ProofItem<StarkHasher>) {
+        let bucket = match item {
+            ProofItem::MerkleRoot(_) => &mut self.merkle_roots,
+            ProofItem::CompressedAuthenticationPaths(_) | ProofItem::AuthenticationPath(_) => {
+                &mut self.authentication_paths
+            }
+            ProofItem::TransposedBaseElementVectors(_)
+            | ProofItem::TransposedExtensionElementVectors(_)
+            | ProofItem::TransposedBaseElements(_)
+            | ProofItem::TransposedExtensionElements(_)
+            | ProofItem::RevealedCombinationElements(_) => &mut self.revealed_elements,
+            ProofItem::FriCodeword(_) => &mut self.fri_codewords,
+            ProofItem::FriProof(_) => &mut self.fri_proofs,
+            ProofItem::FriResponse { .. } => &mut self.fri_responses,
+            ProofItem::PaddedHeight(_)
+            | ProofItem::Claim(_)
+            | ProofItem::ProofParams(_)
+            | ProofItem::Program(_)
+            | ProofItem::PublicInput(_)
+            | ProofItem::PublicOutput(_) => &mut self.metadata,
+            #[cfg(feature = "debug-challenge")]
+            ProofItem::DebugChallenge(_) => &mut self.metadata,
+        };
+        *bucket += item.byte_len();
+    }
+
+    pub fn total(&self) -> usize {
+        self.merkle_roots
+            + self.authentication_paths
+            + self.revealed_elements
+            + self.fri_codewords
+            + self.fri_proofs
+            + self.fri_responses
+            + self.metadata
+    }
+}
+
+impl Display for ProofSizeReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let total = self.total();
+        let percentage = |bytes: usize| match total {
+            0 => 0.0,
+            _ => 100.0 * bytes as f64 / total as f64,
+        };
+        for (name, bytes) in [
+            ("merkle roots", self.merkle_roots),
+            ("authentication paths", self.authentication_paths),
+            ("revealed elements", self.revealed_elements),
+            ("FRI codewords", self.fri_codewords),
+            ("FRI proofs", self.fri_proofs),
+            ("FRI responses", self.fri_responses),
+            ("metadata", self.metadata),
+        ] {
+            writeln!(
+                f,
+                "{name:>20}: {bytes:>10} bytes ({:5.1}%)",
+                percentage(bytes)
+            )?;
+        }
+        write!(f, "{:>20}: {total:>10} bytes", "total")
+    }
+}
+
+/// The parameters a proof was produced with. Proofs can only be aggregated or compared if their
+/// parameters are compatible, see `is_compatible_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProofParams {
+    pub field_modulus: u64,
+    pub extension_degree: usize,
+    pub expansion_factor: usize,
+    pub num_trace_randomizers: usize,
+    pub num_randomizer_polynomials: usize,
+    pub security_level: usize,
+}
+
+/// The proof is about a different claim than the one the verifier expects. The `part` of the
+/// claim is one of the program, the public input, or the public output. `index` is the first
+/// position in which the two differ; a value is `None` if that side ends before `index`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimMismatch {
+    pub part: &'static str,
+    pub expected_length: usize,
+    pub actual_length: usize,
+    pub index: usize,
+    pub expected_value: Option<BFieldElement>,
+    pub actual_value: Option<BFieldElement>,
+}
+
+impl ClaimMismatch {
+    fn check(
+        part: &'static str,
+        expected: &[BFieldElement],
+        actual: &[BFieldElement],
+    ) -> Result<(), Self> {
+        if expected == actual {
+            return Ok(());
+        }
+        let index = expected
+            .iter()
+            .zip(actual)
+            .position(|(expected, actual)| expected != actual)
+            .unwrap_or_else(|| expected.len().min(actual.len()));
+        Err(ClaimMismatch {
+            part,
+            expected_length: expected.len(),
+            actual_length: actual.len(),
+            index,
+            expected_value: expected.get(index).copied(),
+            actual_value: actual.get(index).copied(),
+        })
+    }
+}
+
+impl Error for ClaimMismatch {}
+
+impl Display for ClaimMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let show = |value: Option<BFieldElement>| match value {
+            Some(value) => value.to_string(),
+            None => "nothing".to_string(),
+        };
+        write!(
+            f,
+            "The proof is about a different {}: it has {} elements, the expected one has {}; \
+            they first differ at index {}, where the proof has {} and the expected one has {}",
+            self.part,
+            self.actual_length,
+            self.expected_length,
+            self.index,
+            show(self.actual_value),
+            show(self.expected_value)
+        )
+    }
+}
+
+/// The first parameter in which two `ProofParams` differ.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamMismatch {
+    pub parameter: &'static str,
+    pub ours: u64,
+    pub theirs: u64,
+}
+
+impl Error for ParamMismatch {}
+
+impl Display for ParamMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Incompatible proof parameters: {} is {}, but {} was expected",
+            self.parameter, self.theirs, self.ours
+        )
+    }
+}
+
+impl ProofParams {
+    /// The number of `BFieldElement`s in the encoding of `ProofParams`.
+    pub const ENCODING_LENGTH: usize = 7;
+
+    fn named_values(&self) -> [(&'static str, u64); 6] {
+        [
+            ("field modulus", self.field_modulus),
+            ("extension degree", self.extension_degree as u64),
+            ("expansion factor", self.expansion_factor as u64),
+            (
+                "number of trace randomizers",
+                self.num_trace_randomizers as u64,
+            ),
+            (
+                "number of randomizer polynomials",
+                self.num_randomizer_polynomials as u64,
+            ),
+            ("security level", self.security_level as u64),
+        ]
+    }
+
+    pub fn is_compatible_with(&self, other: &ProofParams) -> Result<(), ParamMismatch> {
+        match self
+            .named_values()
+            .into_iter()
+            .zip_eq(other.named_values())
+            .find(|((_, ours), (_, theirs))| ours != theirs)
+        {
+            Some(((parameter, ours), (_, theirs))) => Err(ParamMismatch {
+                parameter,
+                ours,
+                theirs,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// The field modulus does not fit into a single `BFieldElement` and is split into two halves.
+    pub fn to_bfield_elements(&self) -> Vec<BFieldElement> {
+        vec![
+            BFieldElement::new(self.field_modulus >> 32),
+            BFieldElement::new(self.field_modulus & u32::MAX as u64),
+            BFieldElement::new(self.extension_degree as u64),
+            BFieldElement::new(self.expansion_factor as u64),
+            BFieldElement::new(self.num_trace_randomizers as u64),
+            BFieldElement::new(self.num_randomizer_polynomials as u64),
+            BFieldElement::new(self.security_level as u64),
+        ]
+    }
+
+    /// The inverse of `to_bfield_elements`. Fails if either half of the field modulus does not
+    /// fit into 32 bits, or if any other parameter does not fit into a `usize`. Hence, every
+    /// encoding decodes to different parameters.
+    pub fn from_bfield_elements(
+        bs: &[BFieldElement; Self::ENCODING_LENGTH],
+    ) -> Result<Self, ProofItemError> {
+        let modulus_half = |b: BFieldElement| match b.value() {
+            half if half <= u32::MAX as u64 => Ok(half),
+            half => Err(ProofItemError::FieldModulusHalfTooLarge(half)),
+        };
+        let param = |b: BFieldElement| {
+            usize::try_from(b.value()).map_err(|_| ProofItemError::ProofParamTooLarge(b.value()))
+        };
+        Ok(ProofParams {
+            field_modulus: (modulus_half(bs[0])? << 32) | modulus_half(bs[1])?,
+            extension_degree: param(bs[2])?,
+            expansion_factor: param(bs[3])?,
+            num_trace_randomizers: param(bs[4])?,
+            num_randomizer_polynomials: param(bs[5])?,
+            security_level: param(bs[6])?,
+        })
+    }
+}
 
 pub struct Stark {
     num_trace_randomizers: usize,
@@ -40,6 +340,7 @@ pub struct Stark {
     max_degree: Degree,
     bfri_domain: FriDomain<BFieldElement>,
     xfri: Fri<StarkHasher>,
+    program: Vec<BFieldElement>,
     input_symbols: Vec<BFieldElement>,
     output_symbols: Vec<BFieldElement>,
 }
@@ -52,6 +353,7 @@ impl Stark {
         log_expansion_factor: usize,
         security_level: usize,
         co_set_fri_offset: BFieldElement,
+        program: &[BFieldElement],
         input_symbols: &[BFieldElement],
         output_symbols: &[BFieldElement],
     ) -> Self {
@@ -107,14 +409,36 @@ impl Stark {
             max_degree,
             bfri_domain,
             xfri,
+            program: program.to_vec(),
             input_symbols: input_symbols.to_vec(),
             output_symbols: output_symbols.to_vec(),
         }
     }
 
+    pub fn proof_params(&self) -> ProofParams {
+        ProofParams {
+            field_modulus: BFieldElement::QUOTIENT,
+            extension_degree: EXTENSION_DEGREE,
+            expansion_factor: self.xfri.expansion_factor,
+            num_trace_randomizers: self.num_trace_randomizers,
+            num_randomizer_polynomials: self.num_randomizer_polynomials,
+            security_level: self.security_level,
+        }
+    }
+
     pub fn prove(&self, base_matrices: BaseMatrices) -> StarkProofStream {
         let mut timer = TimingReporter::start();
 
+        debug_assert_eq!(
+            self.program,
+            base_matrices
+                .program_matrix
+                .iter()
+                .map(|row| row[usize::from(ProgramBaseTableColumn::Instruction)])
+                .collect_vec(),
+            "The base matrices must be derived from the Stark's program"
+        );
+
         let base_tables = self.get_padded_base_tables(&base_matrices);
         timer.elapsed("pad");
 
@@ -135,8 +459,13 @@ impl Stark {
         let base_merkle_tree_root = base_tree.get_root();
         timer.elapsed("base_merkle_tree");
 
-        // Commit to base codewords
         let mut proof_stream = StarkProofStream::default();
+        proof_stream.enqueue(&ProofItem::ProofParams(self.proof_params()));
+        proof_stream.enqueue(&ProofItem::Program(self.program.clone()));
+        proof_stream.enqueue(&ProofItem::PublicInput(self.input_symbols.clone()));
+        proof_stream.enqueue(&ProofItem::PublicOutput(self.output_symbols.clone()));
+
+        // Commit to base codewords
         proof_stream.enqueue(&ProofItem::MerkleRoot(base_merkle_tree_root));
         timer.elapsed("proof_stream.enqueue");
 
@@ -322,12 +651,10 @@ impl Stark {
             .collect();
         let revealed_combination_auth_paths =
             combination_tree.get_authentication_structure(&cross_codeword_slice_indices);
-        proof_stream.enqueue(&ProofItem::RevealedCombinationElements(
-            revealed_combination_elements,
-        ));
-        proof_stream.enqueue(&ProofItem::CompressedAuthenticationPaths(
-            revealed_combination_auth_paths,
-        ));
+        proof_stream.enqueue(&ProofItem::FriResponse {
+            paths: revealed_combination_auth_paths,
+            leaves: revealed_combination_elements,
+        });
 
         timer.elapsed("open combination codeword at same positions");
 
@@ -619,6 +946,16 @@ impl Stark {
         let mut timer = TimingReporter::start();
         let hasher = StarkHasher::new();
 
+        let proof_params = proof_stream.dequeue()?.as_proof_params()?;
+        self.proof_params().is_compatible_with(&proof_params)?;
+
+        let program = proof_stream.dequeue()?.as_program()?;
+        ClaimMismatch::check("program", &self.program, &program)?;
+        let public_input = proof_stream.dequeue()?.as_public_input()?;
+        ClaimMismatch::check("public input", &self.input_symbols, &public_input)?;
+        let public_output = proof_stream.dequeue()?.as_public_output()?;
+        ClaimMismatch::check("public output", &self.output_symbols, &public_output)?;
+
         let base_merkle_tree_root = proof_stream.dequeue()?.as_merkle_root()?;
         let extension_challenge_seed = proof_stream.verifier_fiat_shamir();
         timer.elapsed("Fiat-Shamir seed for extension challenges");
@@ -631,7 +968,7 @@ impl Stark {
         let extension_challenges = AllChallenges::create_challenges(extension_challenge_weights);
         timer.elapsed("Create extension challenges");
 
-        let padded_height = proof_stream.dequeue()?.as_padded_heights()?.value() as usize;
+        let padded_height = proof_stream.dequeue()?.as_padded_height_usize()?;
         timer.elapsed("Got padded height");
 
         let extension_tree_merkle_root = proof_stream.dequeue()?.as_merkle_root()?;
@@ -721,12 +1058,16 @@ impl Stark {
         timer.elapsed("Calculated revealed indices");
 
         // TODO: in the following ~80 lines, we (conceptually) do the same thing three times. DRY.
+        let base_row_width = EXTENSION_DEGREE * self.num_randomizer_polynomials
+            + ext_table_collection
+                .into_iter()
+                .map(|table| table.base_width())
+                .sum::<usize>();
         let revealed_base_elems = proof_stream
             .dequeue()?
-            .as_transposed_base_element_vectors()?;
-        let auth_paths_base = proof_stream
-            .dequeue()?
-            .as_compressed_authentication_paths()?;
+            .as_transposed_base_element_vectors_with_width(base_row_width)?;
+        let auth_paths_base_item = proof_stream.dequeue()?;
+        let auth_paths_base = auth_paths_base_item.as_compressed_authentication_paths_ref()?;
         timer.elapsed("Read base elements and auth paths from proof stream");
         let leaf_digests_base: Vec<_> = revealed_base_elems
             .par_iter()
@@ -738,19 +1079,22 @@ impl Stark {
             base_merkle_tree_root,
             &revealed_indices,
             &leaf_digests_base,
-            &auth_paths_base,
+            auth_paths_base,
         ) {
             // TODO: Replace this by a specific error type, or just return `Ok(false)`
             panic!("Failed to verify authentication path for base codeword");
         }
         timer.elapsed(&format!("Verified auth paths for {num_idxs} base elements"));
 
+        let ext_row_width = ext_table_collection
+            .into_iter()
+            .map(|table| table.full_width() - table.base_width())
+            .sum();
         let revealed_ext_elems = proof_stream
             .dequeue()?
-            .as_transposed_extension_element_vectors()?;
-        let auth_paths_ext = proof_stream
-            .dequeue()?
-            .as_compressed_authentication_paths()?;
+            .as_transposed_extension_element_vectors_with_width(ext_row_width)?;
+        let auth_paths_ext_item = proof_stream.dequeue()?;
+        let auth_paths_ext = auth_paths_ext_item.as_compressed_authentication_paths_ref()?;
         timer.elapsed("Read extension elements and auth paths from proof stream");
         let leaf_digests_ext: Vec<_> = revealed_ext_elems
             .par_iter()
@@ -768,7 +1112,7 @@ impl Stark {
             extension_tree_merkle_root,
             &revealed_indices,
             &leaf_digests_ext,
-            &auth_paths_ext,
+            auth_paths_ext,
         ) {
             // TODO: Replace this by a specific error type, or just return `Ok(false)`
             panic!("Failed to verify authentication path for extension codeword");
@@ -776,15 +1120,12 @@ impl Stark {
         timer.elapsed(&format!("Verified auth paths for {num_idxs} ext elements"));
 
         // Verify Merkle authentication path for combination elements
-        let revealed_combination_leafs =
-            proof_stream.dequeue()?.as_revealed_combination_elements()?;
+        let (revealed_combination_auth_paths, revealed_combination_leafs) =
+            proof_stream.dequeue()?.as_fri_response()?;
         let revealed_combination_digests: Vec<_> = revealed_combination_leafs
             .par_iter()
             .map(|xfe| hasher.hash_sequence(&xfe.to_sequence()))
             .collect();
-        let revealed_combination_auth_paths = proof_stream
-            .dequeue()?
-            .as_compressed_authentication_paths()?;
         if !MerkleTree::<StarkHasher>::verify_authentication_structure_from_leaves(
             combination_root,
             &combination_check_indices,
@@ -968,7 +1309,7 @@ impl Stark {
             summands.push(grand_cross_table_arg_quotient);
             summands.push(grand_cross_table_arg_quotient_shifted);
 
-            let inner_product = non_lin_combi_weights
+            let inner_product: XFieldElement = non_lin_combi_weights
                 .par_iter()
                 .zip_eq(summands.par_iter())
                 .map(|(&weight, &summand)| weight * summand)
@@ -976,8 +1317,11 @@ impl Stark {
 
             // FIXME: This assert looks like it's for development, but it's the actual integrity
             //  check. Change to `if (…) { return Ok(false) }` or whatever is suitable.
-            assert_eq!(
-                revealed_combination_leaf, inner_product,
+            assert!(
+                ct_eq(
+                    &revealed_combination_leaf.coefficients,
+                    &inner_product.coefficients
+                ),
                 "The combination leaf must equal the inner product"
             );
         }
@@ -1018,6 +1362,38 @@ impl Stark {
     fn num_trace_randomizers(security_level: usize) -> usize {
         2 * security_level
     }
+
+    /// A static estimate of the number of field multiplications needed to prove a trace with
+    /// `padded_height`-many rows and `total_columns`-many columns. Intended for modeling proving
+    /// cost across parameter choices, not as an exact count. The estimate is the sum of
+    /// 1. interpolating every column over the trace domain, `N·log(N)` per column,
+    /// 1. evaluating every column on the FRI domain of length `D = N·expansion_factor`,
+    ///    `D·log(D)` per column,
+    /// 1. dividing out the zerofiers to get the quotients, `D` per column,
+    /// 1. folding the codeword in every FRI round, `2·L` for a codeword of length `L`, and
+    /// 1. computing the colinear values of every query in every FRI round, `3` per check.
+    pub fn estimate_field_mults(
+        padded_height: usize,
+        total_columns: usize,
+        expansion_factor: usize,
+        fri_rounds: usize,
+        num_queries: usize,
+    ) -> u64 {
+        let log_2 = |n: u64| if n <= 1 { 0 } else { n.ilog2() as u64 };
+        let trace_domain_length = padded_height as u64;
+        let fri_domain_length = trace_domain_length * expansion_factor as u64;
+        let num_columns = total_columns as u64;
+
+        let interpolation = num_columns * trace_domain_length * log_2(trace_domain_length);
+        let evaluation = num_columns * fri_domain_length * log_2(fri_domain_length);
+        let quotients = num_columns * fri_domain_length;
+        let fri_folding: u64 = (0..fri_rounds)
+            .map(|round| 2 * (fri_domain_length >> round))
+            .sum();
+        let fri_queries = 3 * (num_queries * fri_rounds) as u64;
+
+        interpolation + evaluation + quotients + fri_folding + fri_queries
+    }
 }
 
 #[cfg(test)]
@@ -1086,6 +1462,7 @@ pub(crate) mod triton_stark_tests {
             log_expansion_factor,
             security_level,
             co_set_fri_offset,
+            &program.to_bwords(),
             input_symbols,
             output_symbols,
         );
@@ -1173,7 +1550,7 @@ pub(crate) mod triton_stark_tests {
 
     #[test]
     pub fn shift_codeword_test() {
-        let stark = Stark::new(2, 1, 2, 32, BFieldElement::one(), &[], &[]);
+        let stark = Stark::new(2, 1, 2, 32, BFieldElement::one(), &[], &[], &[]);
         let fri_x_values = stark.xfri.domain.domain_values();
 
         let mut test_codeword: Vec<XFieldElement> = vec![0.into(); stark.xfri.domain.length];
@@ -1201,6 +1578,214 @@ pub(crate) mod triton_stark_tests {
         }
     }
 
+    #[test]
+    fn total_byte_len_sums_item_byte_lens_test() {
+        let items: Vec<ProofItem<StarkHasher>> = vec![
+            ProofItem::Claim(vec![BFieldElement::new(1), BFieldElement::new(2)]),
+            ProofItem::PaddedHeight(BFieldElement::new(16)),
+            ProofItem::FriCodeword(vec![XFieldElement::one(); 4]),
+        ];
+        let mut proof_stream = StarkProofStream::default();
+        assert_eq!(0, proof_stream.total_byte_len());
+        for item in items.iter() {
+            proof_stream.enqueue(item);
+        }
+        let expected: usize = items.iter().map(|item| item.byte_len()).sum();
+        assert_eq!(8 * (2 + 1 + 12), expected);
+        assert_eq!(expected, proof_stream.total_byte_len());
+    }
+
+    #[test]
+    fn transcript_summary_test() {
+        let items: Vec<ProofItem<StarkHasher>> = vec![
+            ProofItem::Claim(vec![BFieldElement::new(1), BFieldElement::new(2)]),
+            ProofItem::MerkleRoot(StarkHasher::new().hash_sequence(&[BFieldElement::new(3)])),
+            ProofItem::FriCodeword(vec![XFieldElement::one(); 4]),
+            ProofItem::PaddedHeight(BFieldElement::new(16)),
+        ];
+        let mut proof_stream = StarkProofStream::default();
+        assert!(proof_stream.transcript_summary().is_empty());
+        for item in items.iter() {
+            proof_stream.enqueue(item);
+        }
+
+        let summary = proof_stream.transcript_summary();
+        assert_eq!(items.len(), summary.len());
+        let expected_summary = vec![
+            (0, "claim", 2),
+            (1, "merkle root", 5),
+            (2, "FRI codeword", 12),
+            (3, "padded table height", 1),
+        ];
+        assert_eq!(expected_summary, summary);
+        assert_eq!(
+            proof_stream.transcript_length(),
+            summary.iter().map(|&(_, _, length)| length).sum::<usize>()
+        );
+
+        // The proof stream is left ready for the verifier.
+        assert_eq!(
+            vec![BFieldElement::new(1), BFieldElement::new(2)],
+            proof_stream.dequeue().unwrap().as_claim().unwrap()
+        );
+    }
+
+    #[test]
+    fn size_report_buckets_items_by_kind_test() {
+        let items: Vec<ProofItem<StarkHasher>> = vec![
+            ProofItem::Claim(vec![BFieldElement::new(1), BFieldElement::new(2)]),
+            ProofItem::PaddedHeight(BFieldElement::new(16)),
+            ProofItem::FriCodeword(vec![XFieldElement::one(); 4]),
+            ProofItem::TransposedBaseElements(vec![BFieldElement::new(3); 5]),
+            ProofItem::RevealedCombinationElements(vec![XFieldElement::one(); 2]),
+        ];
+        let mut proof_stream = StarkProofStream::default();
+        assert_eq!(ProofSizeReport::default(), proof_stream.size_report());
+        for item in items.iter() {
+            proof_stream.enqueue(item);
+        }
+
+        let size_report = proof_stream.size_report();
+        let expected_report = ProofSizeReport {
+            revealed_elements: 8 * (5 + 6),
+            fri_codewords: 8 * 12,
+            metadata: 8 * (2 + 1),
+            ..ProofSizeReport::default()
+        };
+        assert_eq!(expected_report, size_report);
+        assert_eq!(proof_stream.total_byte_len(), size_report.total());
+
+        // The proof stream is left ready for the verifier.
+        assert_eq!(
+            vec![BFieldElement::new(1), BFieldElement::new(2)],
+            proof_stream.dequeue().unwrap().as_claim().unwrap()
+        );
+    }
+
+    #[test]
+    fn proof_stream_with_alternate_hasher_test() {
+        let mut proof_stream = StarkProofStream::<blake3::Hasher>::default();
+        let padded_height = BFieldElement::new(16);
+        proof_stream.enqueue(&ProofItem::PaddedHeight(padded_height));
+
+        // Blake3 absorbs bytes, i.e., 8 transcript elements per `BFieldElement`.
+        assert_eq!(8, proof_stream.transcript_length());
+        let dequeued_item = proof_stream.dequeue().unwrap();
+        assert_eq!(padded_height, dequeued_item.as_padded_heights().unwrap());
+        assert_eq!(
+            proof_stream.prover_fiat_shamir(),
+            proof_stream.verifier_fiat_shamir()
+        );
+    }
+
+    #[test]
+    fn verifier_rejects_proof_for_different_claim_test() {
+        let program = Program::from_code("push 1 push 2 add halt")
+            .unwrap()
+            .to_bwords();
+        let other_program = Program::from_code("push 1 push 3 add halt")
+            .unwrap()
+            .to_bwords();
+        let stark = Stark::new(2, 1, 2, 32, BFieldElement::one(), &program, &[], &[]);
+
+        let mut proof_stream = StarkProofStream::default();
+        proof_stream.enqueue(&ProofItem::ProofParams(stark.proof_params()));
+        proof_stream.enqueue(&ProofItem::Program(other_program.clone()));
+        let err = stark.verify(&mut proof_stream).unwrap_err();
+        let mismatch = err.downcast_ref::<ClaimMismatch>().unwrap();
+        assert_eq!("program", mismatch.part);
+        assert_eq!(program.len(), mismatch.expected_length);
+        assert_eq!(program.len(), mismatch.actual_length);
+        assert_eq!(3, mismatch.index);
+        assert_eq!(Some(BFieldElement::new(2)), mismatch.expected_value);
+        assert_eq!(Some(BFieldElement::new(3)), mismatch.actual_value);
+        assert_eq!(program[..3], other_program[..3]);
+
+        let output = [BFieldElement::new(3)];
+        let stark = Stark::new(2, 1, 2, 32, BFieldElement::one(), &program, &[], &output);
+        let mut proof_stream = StarkProofStream::default();
+        proof_stream.enqueue(&ProofItem::ProofParams(stark.proof_params()));
+        proof_stream.enqueue(&ProofItem::Program(program.clone()));
+        proof_stream.enqueue(&ProofItem::PublicInput(vec![]));
+        proof_stream.enqueue(&ProofItem::PublicOutput(vec![BFieldElement::new(4)]));
+        let err = stark.verify(&mut proof_stream).unwrap_err();
+        let mismatch = err.downcast_ref::<ClaimMismatch>().unwrap();
+        assert_eq!("public output", mismatch.part);
+        assert_eq!(0, mismatch.index);
+        assert_eq!(Some(BFieldElement::new(3)), mismatch.expected_value);
+        assert_eq!(Some(BFieldElement::new(4)), mismatch.actual_value);
+
+        // The program is absorbed before the first challenges are sampled.
+        let fiat_shamir_seed = |program: &[BFieldElement]| {
+            let mut proof_stream: StarkProofStream = StarkProofStream::default();
+            proof_stream.enqueue(&ProofItem::Program(program.to_vec()));
+            proof_stream.prover_fiat_shamir()
+        };
+        assert_ne!(fiat_shamir_seed(&program), fiat_shamir_seed(&other_program));
+    }
+
+    #[test]
+    fn claim_mismatch_of_prefix_points_past_the_shorter_side_test() {
+        let expected = [1, 2].map(BFieldElement::new);
+        let actual = [1, 2, 3].map(BFieldElement::new);
+        let mismatch = ClaimMismatch::check("public input", &expected, &actual).unwrap_err();
+        assert_eq!(2, mismatch.index);
+        assert_eq!(None, mismatch.expected_value);
+        assert_eq!(Some(BFieldElement::new(3)), mismatch.actual_value);
+        assert!(mismatch.to_string().contains("index 2"));
+        assert!(ClaimMismatch::check("public input", &expected, &expected).is_ok());
+    }
+
+    #[test]
+    fn proof_params_compatibility_test() {
+        let stark = Stark::new(2, 1, 2, 32, BFieldElement::one(), &[], &[], &[]);
+        let params = stark.proof_params();
+        assert_eq!(Ok(()), params.is_compatible_with(&params));
+
+        let other_params = ProofParams {
+            expansion_factor: 8,
+            num_trace_randomizers: 1,
+            ..params
+        };
+        let mismatch = params.is_compatible_with(&other_params).unwrap_err();
+        assert_eq!("expansion factor", mismatch.parameter);
+        assert_eq!(4, mismatch.ours);
+        assert_eq!(8, mismatch.theirs);
+
+        let encoding = params.to_bfield_elements();
+        assert_eq!(ProofParams::ENCODING_LENGTH, encoding.len());
+        let decoded = ProofParams::from_bfield_elements(&encoding.clone().try_into().unwrap());
+        assert_eq!(Ok(params), decoded);
+
+        // Halves of the field modulus that exceed 32 bits would merge into other encodings.
+        for half_index in [0, 1] {
+            let mut bad_encoding = encoding.clone();
+            bad_encoding[half_index] = BFieldElement::new(1 << 32);
+            assert_eq!(
+                Err(ProofItemError::FieldModulusHalfTooLarge(1 << 32)),
+                ProofParams::from_bfield_elements(&bad_encoding.try_into().unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn estimate_field_mults_test() {
+        assert_eq!(0, Stark::estimate_field_mults(0, 0, 4, 0, 0));
+
+        // 2 columns of height 8, FRI domain of length 32
+        // interpolation: 2·8·3, evaluation: 2·32·5, quotients: 2·32,
+        // folding: 2·(32 + 16), queries: 3·4·2
+        let expected = 48 + 320 + 64 + 96 + 24;
+        assert_eq!(expected, Stark::estimate_field_mults(8, 2, 4, 2, 4));
+
+        let base = Stark::estimate_field_mults(1 << 10, 100, 4, 8, 40);
+        assert!(base < Stark::estimate_field_mults(1 << 11, 100, 4, 8, 40));
+        assert!(base < Stark::estimate_field_mults(1 << 10, 101, 4, 8, 40));
+        assert!(base < Stark::estimate_field_mults(1 << 10, 100, 8, 8, 40));
+        assert!(base < Stark::estimate_field_mults(1 << 10, 100, 4, 9, 40));
+        assert!(base < Stark::estimate_field_mults(1 << 10, 100, 4, 8, 41));
+    }
+
     // 1. simulate(), pad(), extend(), test terminals
     #[test]
     pub fn check_io_terminals() {
@@ -1395,6 +1980,10 @@ pub(crate) mod triton_stark_tests {
             &[],
         );
 
+        let size_report = proof_stream.size_report();
+        println!("{size_report}");
+        assert_eq!(proof_stream.total_byte_len(), size_report.total());
+
         println!("between prove and verify");
 
         let result = stark.verify(&mut proof_stream);
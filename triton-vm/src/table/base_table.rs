@@ -1,11 +1,20 @@
 use super::super::fri_domain::FriDomain;
 use itertools::Itertools;
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use std::ops::Range;
+use num_traits::Zero;
+use rand::RngCore;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::iter::successors;
+use std::ops::{Deref, DerefMut, Range};
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::mpolynomial::{Degree, MPolynomial};
+use twenty_first::shared_math::ntt::intt;
 use twenty_first::shared_math::polynomial::Polynomial;
-use twenty_first::shared_math::traits::{FiniteField, GetRandomElements};
+use twenty_first::shared_math::traits::{FiniteField, GetRandomElements, Inverse, ModPowU32};
 use twenty_first::shared_math::x_field_element::XFieldElement;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -33,6 +42,237 @@ pub struct Table<FieldElement: FiniteField> {
     pub(crate) consistency_quotient_degree_bounds: Option<Vec<i64>>,
     pub(crate) transition_quotient_degree_bounds: Option<Vec<i64>>,
     pub(crate) terminal_quotient_degree_bounds: Option<Vec<i64>>,
+
+    /// Interpolants computed by `interpolate_columns_cached`, to be reused by subsequent calls
+    interpolant_cache: InterpolantCache<FieldElement>,
+}
+
+/// The interpolants cached on a `Table`. Whether interpolants are cached says nothing about the
+/// table itself, so any two caches compare equal. This keeps the cache out of `Table`'s equality.
+#[derive(Debug, Clone)]
+struct InterpolantCache<FieldElement: FiniteField>(Vec<CachedInterpolants<FieldElement>>);
+
+impl<FieldElement: FiniteField> Default for InterpolantCache<FieldElement> {
+    fn default() -> Self {
+        Self(vec![])
+    }
+}
+
+impl<FieldElement: FiniteField> PartialEq for InterpolantCache<FieldElement> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<FieldElement: FiniteField> Eq for InterpolantCache<FieldElement> {}
+
+impl<FieldElement: FiniteField> Deref for InterpolantCache<FieldElement> {
+    type Target = Vec<CachedInterpolants<FieldElement>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<FieldElement: FiniteField> DerefMut for InterpolantCache<FieldElement> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// The interpolants of the columns in `columns`, together with the parameters they were
+/// interpolated with. The FRI domain's `offset` and `length` are part of the key, since the
+/// trace randomizers are placed on the FRI domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CachedInterpolants<FieldElement: FiniteField> {
+    fri_domain_offset: FieldElement,
+    fri_domain_length: usize,
+    omicron: FieldElement,
+    padded_height: usize,
+    num_trace_randomizers: usize,
+    columns: Range<usize>,
+    interpolants: Vec<Polynomial<FieldElement>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BaseTableError {
+    EmptyMatrix,
+    RaggedRow {
+        row_index: usize,
+        expected_width: usize,
+        actual_width: usize,
+    },
+    RaggedColumn {
+        column_index: usize,
+        expected_height: usize,
+        actual_height: usize,
+    },
+    WindowOutOfBounds {
+        rows: Range<usize>,
+        cols: Range<usize>,
+        height: usize,
+        width: usize,
+    },
+    UnexpectedRowWidth {
+        row_index: usize,
+        expected_width: usize,
+        actual_width: usize,
+    },
+    HeightNotPowerOfTwo(usize),
+    DegenerateConstraint {
+        constraint_index: usize,
+        degree_bound: Degree,
+    },
+    UnexpectedConstraintArity {
+        constraint_index: usize,
+        expected_variable_count: usize,
+        actual_variable_count: usize,
+    },
+    NotPadded {
+        name: String,
+        expected: usize,
+        got: usize,
+    },
+    ColumnOutOfBounds {
+        name: String,
+        column: usize,
+        width: usize,
+    },
+    ConstraintsNotPopulated(ConstraintCategory),
+    NoSuccessorRow {
+        row: usize,
+        height: usize,
+    },
+}
+
+impl Error for BaseTableError {}
+
+impl Display for BaseTableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaseTableError::EmptyMatrix => write!(f, "The matrix has no rows"),
+            BaseTableError::RaggedRow {
+                row_index,
+                expected_width,
+                actual_width,
+            } => write!(
+                f,
+                "Row {row_index} has width {actual_width}, but previous rows have width {expected_width}"
+            ),
+            BaseTableError::RaggedColumn {
+                column_index,
+                expected_height,
+                actual_height,
+            } => write!(
+                f,
+                "Column {column_index} has height {actual_height}, but previous columns have \
+                height {expected_height}"
+            ),
+            BaseTableError::WindowOutOfBounds {
+                rows,
+                cols,
+                height,
+                width,
+            } => write!(
+                f,
+                "Window of rows {rows:?} and columns {cols:?} does not fit into table of height \
+                {height} and width {width}"
+            ),
+            BaseTableError::UnexpectedRowWidth {
+                row_index,
+                expected_width,
+                actual_width,
+            } => write!(
+                f,
+                "Row {row_index} has width {actual_width}, but the table's width is {expected_width}"
+            ),
+            BaseTableError::HeightNotPowerOfTwo(height) => {
+                write!(f, "The table's height {height} is not a power of two")
+            }
+            BaseTableError::DegenerateConstraint {
+                constraint_index,
+                degree_bound,
+            } => write!(
+                f,
+                "Constraint {constraint_index} is constant, which results in the invalid quotient \
+                degree bound {degree_bound}"
+            ),
+            BaseTableError::UnexpectedConstraintArity {
+                constraint_index,
+                expected_variable_count,
+                actual_variable_count,
+            } => write!(
+                f,
+                "Constraint {constraint_index} has {actual_variable_count} variables, but \
+                {expected_variable_count} were expected"
+            ),
+            BaseTableError::NotPadded {
+                name,
+                expected,
+                got,
+            } => write!(
+                f,
+                "{name}: Table data must be padded before interpolation: expected height \
+                {expected}, got {got}"
+            ),
+            BaseTableError::ColumnOutOfBounds {
+                name,
+                column,
+                width,
+            } => write!(
+                f,
+                "{name}: Column {column} is out of bounds for table of width {width}"
+            ),
+            BaseTableError::ConstraintsNotPopulated(category) => {
+                write!(f, "The table's {category}s are not populated")
+            }
+            BaseTableError::NoSuccessorRow { row, height } => write!(
+                f,
+                "Row {row} has no successor row in table of height {height}"
+            ),
+        }
+    }
+}
+
+/// The name of a table without the suffixes added by `with_data` and `new_from_lifted_matrix`.
+fn base_table_name(name: &str) -> &str {
+    let mut name = name;
+    while let Some(stripped) = name
+        .strip_suffix(" with data")
+        .or_else(|| name.strip_suffix(" with lifted matrix"))
+    {
+        name = stripped;
+    }
+    name
+}
+
+/// The AIR constraints of a table, grouped by the rows they apply to. See `ConstraintCategory`.
+#[derive(Debug, Clone, Default)]
+pub struct ConstraintSets {
+    pub initial: Vec<MPolynomial<XFieldElement>>,
+    pub consistency: Vec<MPolynomial<XFieldElement>>,
+    pub transition: Vec<MPolynomial<XFieldElement>>,
+    pub terminal: Vec<MPolynomial<XFieldElement>>,
+}
+
+/// The kinds of AIR constraints a table can have, distinguished by the rows they apply to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConstraintCategory {
+    Initial,
+    Consistency,
+    Transition,
+    Terminal,
+}
+
+impl Display for ConstraintCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstraintCategory::Initial => write!(f, "initial constraint"),
+            ConstraintCategory::Consistency => write!(f, "consistency constraint"),
+            ConstraintCategory::Transition => write!(f, "transition constraint"),
+            ConstraintCategory::Terminal => write!(f, "terminal constraint"),
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -43,6 +283,10 @@ impl<DataPF: FiniteField> Table<DataPF> {
         matrix: Vec<Vec<DataPF>>,
         name: String,
     ) -> Self {
+        assert!(
+            full_width >= base_width,
+            "Table {name}: full width {full_width} must not be smaller than base width {base_width}"
+        );
         Table {
             base_width,
             full_width,
@@ -56,17 +300,192 @@ impl<DataPF: FiniteField> Table<DataPF> {
             consistency_quotient_degree_bounds: None,
             transition_quotient_degree_bounds: None,
             terminal_quotient_degree_bounds: None,
+            interpolant_cache: InterpolantCache::default(),
+        }
+    }
+
+    /// Like `new`, but for column-major data, i.e., one vector per column. Transposes the
+    /// `columns` into the row-major matrix. Fails if the columns differ in length.
+    pub fn from_columns(
+        base_width: usize,
+        full_width: usize,
+        columns: Vec<Vec<DataPF>>,
+        name: String,
+    ) -> Result<Self, BaseTableError> {
+        let height = columns.first().map_or(0, |column| column.len());
+        if let Some((column_index, column)) = columns
+            .iter()
+            .find_position(|column| column.len() != height)
+        {
+            return Err(BaseTableError::RaggedColumn {
+                column_index,
+                expected_height: height,
+                actual_height: column.len(),
+            });
+        }
+        let matrix = (0..height)
+            .map(|row_index| columns.iter().map(|column| column[row_index]).collect())
+            .collect();
+        Ok(Self::new(base_width, full_width, matrix, name))
+    }
+
+    /// The common width of all rows in the `matrix`. Useful for reconstructing a table via `new`
+    /// from a matrix whose width metadata was lost. Fails if the matrix is empty or ragged.
+    pub fn infer_widths_from_matrix(matrix: &[Vec<DataPF>]) -> Result<usize, BaseTableError> {
+        let width = matrix.first().ok_or(BaseTableError::EmptyMatrix)?.len();
+        match matrix.iter().position(|row| row.len() != width) {
+            Some(row_index) => Err(BaseTableError::RaggedRow {
+                row_index,
+                expected_width: width,
+                actual_width: matrix[row_index].len(),
+            }),
+            None => Ok(width),
         }
     }
 
-    /// Create a `BaseTable<DataPF>` with the same parameters, but new `matrix` data.
+    /// Create a `BaseTable<DataPF>` with the same parameters, but new `matrix` data. The name is
+    /// derived from the table's base name, such that repeated calls do not keep growing it.
     pub fn with_data(&self, matrix: Vec<Vec<DataPF>>) -> Self {
+        let name = format!("{} with data", base_table_name(&self.name));
+        self.with_data_named(matrix, name)
+    }
+
+    /// Like `with_data`, but the new table is called `name`.
+    pub fn with_data_named(&self, matrix: Vec<Vec<DataPF>>, name: String) -> Self {
         Table {
             matrix,
-            name: format!("{} with data", self.name),
+            name,
+            interpolant_cache: InterpolantCache::default(),
             ..self.to_owned()
         }
     }
+
+    /// The degree bounds of the quotients of the initial constraints. `None` until the table is
+    /// extended.
+    pub fn initial_quotient_degree_bounds(&self) -> Option<&[Degree]> {
+        self.initial_quotient_degree_bounds.as_deref()
+    }
+
+    /// Like `initial_quotient_degree_bounds`, but for the consistency constraints.
+    pub fn consistency_quotient_degree_bounds(&self) -> Option<&[Degree]> {
+        self.consistency_quotient_degree_bounds.as_deref()
+    }
+
+    /// Like `initial_quotient_degree_bounds`, but for the transition constraints.
+    pub fn transition_quotient_degree_bounds(&self) -> Option<&[Degree]> {
+        self.transition_quotient_degree_bounds.as_deref()
+    }
+
+    /// Like `initial_quotient_degree_bounds`, but for the terminal constraints.
+    pub fn terminal_quotient_degree_bounds(&self) -> Option<&[Degree]> {
+        self.terminal_quotient_degree_bounds.as_deref()
+    }
+
+    /// The category and index of every populated AIR constraint that is the zero polynomial.
+    /// Such a constraint enforces nothing and most likely indicates a bug in its definition.
+    pub fn trivial_constraints(&self) -> Vec<(ConstraintCategory, usize)> {
+        [
+            (ConstraintCategory::Initial, &self.initial_constraints),
+            (
+                ConstraintCategory::Consistency,
+                &self.consistency_constraints,
+            ),
+            (ConstraintCategory::Transition, &self.transition_constraints),
+            (ConstraintCategory::Terminal, &self.terminal_constraints),
+        ]
+        .into_iter()
+        .filter_map(|(category, constraints)| constraints.as_ref().map(|cs| (category, cs)))
+        .flat_map(|(category, constraints)| {
+            constraints
+                .iter()
+                .enumerate()
+                .filter(|(_, constraint)| constraint.is_zero())
+                .map(move |(idx, _)| (category, idx))
+        })
+        .collect()
+    }
+
+    /// Evaluate the terminal constraints on the last row of the table. All residuals being zero
+    /// means the terminal conditions hold. Returns `None` if the terminal constraints are not yet
+    /// populated or if the table is empty.
+    pub fn terminal_residuals(&self) -> Option<Vec<DataPF>> {
+        self.evaluate_terminal_constraints().ok()
+    }
+
+    /// Evaluate the initial constraints on the first row of the table. For a valid trace, every
+    /// value is zero. The index of a non-zero value is the index of the violated constraint.
+    pub fn evaluate_initial_constraints(&self) -> Result<Vec<DataPF>, BaseTableError> {
+        let initial_constraints =
+            self.initial_constraints
+                .as_ref()
+                .ok_or(BaseTableError::ConstraintsNotPopulated(
+                    ConstraintCategory::Initial,
+                ))?;
+        let first_row = self.matrix.first().ok_or(BaseTableError::EmptyMatrix)?;
+        Ok(initial_constraints
+            .iter()
+            .map(|constraint| constraint.evaluate(first_row))
+            .collect())
+    }
+
+    /// Evaluate the transition constraints on the concatenation of `row` and its successor row.
+    /// For a valid trace, every value is zero for every row but the last, which has no successor.
+    pub fn evaluate_transition_constraints(
+        &self,
+        row: usize,
+    ) -> Result<Vec<DataPF>, BaseTableError> {
+        let transition_constraints =
+            self.transition_constraints
+                .as_ref()
+                .ok_or(BaseTableError::ConstraintsNotPopulated(
+                    ConstraintCategory::Transition,
+                ))?;
+        let height = self.matrix.len();
+        if row + 1 >= height {
+            return Err(BaseTableError::NoSuccessorRow { row, height });
+        }
+        let row_pair = [self.matrix[row].as_slice(), self.matrix[row + 1].as_slice()].concat();
+        Ok(transition_constraints
+            .iter()
+            .map(|constraint| constraint.evaluate(&row_pair))
+            .collect())
+    }
+
+    /// Like `evaluate_initial_constraints`, but for the terminal constraints and the last row.
+    pub fn evaluate_terminal_constraints(&self) -> Result<Vec<DataPF>, BaseTableError> {
+        let terminal_constraints =
+            self.terminal_constraints
+                .as_ref()
+                .ok_or(BaseTableError::ConstraintsNotPopulated(
+                    ConstraintCategory::Terminal,
+                ))?;
+        let last_row = self.matrix.last().ok_or(BaseTableError::EmptyMatrix)?;
+        Ok(terminal_constraints
+            .iter()
+            .map(|constraint| constraint.evaluate(last_row))
+            .collect())
+    }
+}
+
+/// The canonical integer representation of a field element, as used in exported traces.
+#[cfg(feature = "debug-export")]
+pub trait CsvField {
+    fn to_csv_field(&self) -> String;
+}
+
+#[cfg(feature = "debug-export")]
+impl CsvField for BFieldElement {
+    fn to_csv_field(&self) -> String {
+        self.value().to_string()
+    }
+}
+
+/// The coefficients, lowest degree first, separated by spaces.
+#[cfg(feature = "debug-export")]
+impl CsvField for XFieldElement {
+    fn to_csv_field(&self) -> String {
+        self.coefficients.iter().map(|c| c.value()).join(" ")
+    }
 }
 
 pub trait InheritsFromTable<DataPF: FiniteField> {
@@ -81,12 +500,184 @@ pub trait InheritsFromTable<DataPF: FiniteField> {
         self.inherited_table().full_width
     }
 
+    /// The indices of the base columns, i.e., `0..base_width`.
+    fn base_columns(&self) -> Range<usize> {
+        0..self.base_width()
+    }
+
+    /// The indices of the extension columns, i.e., `base_width..full_width`.
+    fn extension_columns(&self) -> Range<usize> {
+        self.base_width()..self.full_width()
+    }
+
     fn data(&self) -> &Vec<Vec<DataPF>> {
         &self.inherited_table().matrix
     }
 
+    /// Mutable access to the table data. Clears the interpolant cache, since the cached
+    /// interpolants might no longer match the data.
     fn mut_data(&mut self) -> &mut Vec<Vec<DataPF>> {
-        &mut self.mut_inherited_table().matrix
+        let table = self.mut_inherited_table();
+        table.interpolant_cache.clear();
+        &mut table.matrix
+    }
+
+    /// Drop all interpolants cached by `interpolate_columns_cached`.
+    fn clear_interpolant_cache(&mut self) {
+        self.mut_inherited_table().interpolant_cache.clear();
+    }
+
+    fn num_rows(&self) -> usize {
+        self.data().len()
+    }
+
+    /// The number of rows and the width of the table, e.g., for logging. The width is that of the
+    /// first row. Without rows, it is the table's full width if its AIR constraints are populated,
+    /// and its base width otherwise.
+    fn shape(&self) -> (usize, usize) {
+        let width = match self.data().first() {
+            Some(row) => row.len(),
+            None => self.expected_row_width(),
+        };
+        (self.num_rows(), width)
+    }
+
+    /// Write the table data as CSV to `writer` for inspection in external tools. The header row
+    /// holds the column indices, and every element is written in its canonical representation.
+    #[cfg(feature = "debug-export")]
+    fn write_csv<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        Self: Sized,
+        DataPF: CsvField,
+    {
+        let (_, width) = self.shape();
+        writeln!(writer, "{}", (0..width).join(","))?;
+        for row in self.data() {
+            writeln!(
+                writer,
+                "{}",
+                row.iter().map(CsvField::to_csv_field).join(",")
+            )?;
+        }
+        Ok(())
+    }
+
+    /// The row at `index`. Panics with the table's name if `index` is out of bounds.
+    fn get_row(&self, index: usize) -> &[DataPF] {
+        let num_rows = self.num_rows();
+        assert!(
+            index < num_rows,
+            "Table {}: row index {index} is out of bounds for {num_rows} rows",
+            self.inherited_table().name
+        );
+        &self.data()[index]
+    }
+
+    /// The rows in `range`. Panics with the table's name if `range` is out of bounds.
+    fn get_rows(&self, range: Range<usize>) -> &[Vec<DataPF>] {
+        let num_rows = self.num_rows();
+        assert!(
+            range.start <= range.end && range.end <= num_rows,
+            "Table {}: row range {range:?} is out of bounds for {num_rows} rows",
+            self.inherited_table().name
+        );
+        &self.data()[range]
+    }
+
+    /// The elements of column `col`, borrowed from the table's rows. Panics with the table's name
+    /// if `col` is out of bounds.
+    fn column(&self, col: usize) -> Box<dyn Iterator<Item = &DataPF> + '_> {
+        let width = self.data().first().map_or(0, |row| row.len());
+        assert!(
+            col < width || self.data().is_empty(),
+            "Table {}: column index {col} is out of bounds for width {width}",
+            self.inherited_table().name
+        );
+        Box::new(self.data().iter().map(move |row| &row[col]))
+    }
+
+    /// An owned copy of column `col`. See `column`.
+    fn column_to_vec(&self, col: usize) -> Vec<DataPF> {
+        self.column(col).copied().collect()
+    }
+
+    /// The width every row must have: the full width if the table's AIR constraints are
+    /// populated, and the base width otherwise.
+    fn expected_row_width(&self) -> usize {
+        let table = self.inherited_table();
+        match table.initial_constraints {
+            Some(_) => table.full_width,
+            None => table.base_width,
+        }
+    }
+
+    /// Append `row` to the table, e.g., while generating the trace. Fails without modifying the
+    /// table if the row does not have the `expected_row_width`.
+    fn push_row(&mut self, row: Vec<DataPF>) -> Result<(), BaseTableError> {
+        let expected_width = self.expected_row_width();
+        if row.len() != expected_width {
+            return Err(BaseTableError::UnexpectedRowWidth {
+                row_index: self.num_rows(),
+                expected_width,
+                actual_width: row.len(),
+            });
+        }
+        self.mut_data().push(row);
+        Ok(())
+    }
+
+    /// Checks that every row has the `expected_row_width` and that the (padded) height is a power
+    /// of two or zero. Calling this before proving turns panics deep inside interpolation into
+    /// actionable errors.
+    fn assert_well_formed(&self) -> Result<(), BaseTableError> {
+        let expected_width = self.expected_row_width();
+        if let Some((row_index, row)) = self
+            .data()
+            .iter()
+            .find_position(|row| row.len() != expected_width)
+        {
+            return Err(BaseTableError::UnexpectedRowWidth {
+                row_index,
+                expected_width,
+                actual_width: row.len(),
+            });
+        }
+
+        let height = self.num_rows();
+        if height != 0 && !height.is_power_of_two() {
+            return Err(BaseTableError::HeightNotPowerOfTwo(height));
+        }
+        Ok(())
+    }
+
+    /// A copy of the rectangular region of the table spanned by `rows` and `cols`. Allows
+    /// inspecting part of a large table without cloning all of it.
+    fn window(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<Vec<Vec<DataPF>>, BaseTableError> {
+        let height = self.data().len();
+        let width = self.data().first().map_or(0, |row| row.len());
+        let rows_fit = rows.start <= rows.end && rows.end <= height;
+        let cols_fit = cols.start <= cols.end
+            && rows_fit
+            && self.data()[rows.clone()]
+                .iter()
+                .all(|row| cols.end <= row.len());
+        if !cols_fit {
+            return Err(BaseTableError::WindowOutOfBounds {
+                rows,
+                cols,
+                height,
+                width,
+            });
+        }
+
+        Ok(self.data()[rows]
+            .iter()
+            .map(|row| row[cols.clone()].to_vec())
+            .collect())
     }
 }
 
@@ -94,8 +685,21 @@ pub trait Extendable: TableLike<BFieldElement> {
     // Abstract functions that individual structs implement
 
     /// Computes some (or all) padding rows and, if appropriate, the index where they are to be
-    /// inserted.
-    fn get_padding_rows(&self) -> (Option<usize>, Vec<Vec<BFieldElement>>);
+    /// inserted. By default, appends the single row derived by `.padding_row_for()` from the
+    /// table's last row.
+    fn get_padding_rows(&self) -> (Option<usize>, Vec<Vec<BFieldElement>>) {
+        let last_row = self.data().last().map(|row| row.as_slice());
+        (None, vec![self.padding_row_for(last_row)])
+    }
+
+    /// Derives the padding row following row `prev`, or the first row of an empty table if `prev`
+    /// is `None`. By default, repeats `prev`, or uses the all-zero row if there is none.
+    fn padding_row_for(&self, prev: Option<&[BFieldElement]>) -> Vec<BFieldElement> {
+        match prev {
+            Some(row) => row.to_vec(),
+            None => vec![BFieldElement::zero(); self.base_width()],
+        }
+    }
 
     // Generic functions common to all extendable tables
 
@@ -104,13 +708,56 @@ pub trait Extendable: TableLike<BFieldElement> {
             self.base_width(),
             self.full_width(),
             matrix,
-            format!("{} with lifted matrix", self.name()),
+            format!("{} with lifted matrix", base_table_name(&self.name())),
         )
     }
+
+    /// The full-width matrix whose rows are the table's base rows, embedded into the extension
+    /// field, followed by the respective row of `extension_rows`. Only the extension columns need
+    /// to be computed by the caller; the result can be passed to `extension`. Panics if there is
+    /// not exactly one extension row of width `full_width - base_width` per base row.
+    fn lifted_matrix_with(
+        &self,
+        extension_rows: Vec<Vec<XFieldElement>>,
+    ) -> Vec<Vec<XFieldElement>> {
+        let num_rows = self.num_rows();
+        assert_eq!(
+            num_rows,
+            extension_rows.len(),
+            "{}: Need one extension row per row",
+            self.name()
+        );
+        let extension_width = self.full_width() - self.base_width();
+        self.data()
+            .iter()
+            .zip_eq(extension_rows)
+            .enumerate()
+            .map(|(row_index, (base_row, extension_row))| {
+                assert_eq!(
+                    extension_width,
+                    extension_row.len(),
+                    "{}: Extension row {row_index} has the wrong width",
+                    self.name()
+                );
+                base_row
+                    .iter()
+                    .map(|bfe| bfe.lift())
+                    .chain(extension_row)
+                    .collect()
+            })
+            .collect()
+    }
     /// Add padding to a table so that its height becomes the same as other tables. Uses
     /// table-specific padding via `.get_padding_rows()`, which might specify an insertion index for
     /// the padding row(s).
     fn pad(&mut self, padded_height: usize) {
+        let height = self.data().len();
+        assert!(
+            height <= padded_height,
+            "{}: Cannot pad table of height {height} to smaller height {padded_height}",
+            self.name()
+        );
+        self.mut_data().reserve_exact(padded_height - height);
         while self.data().len() != padded_height {
             let (maybe_index, mut rows) = self.get_padding_rows();
             match maybe_index {
@@ -127,15 +774,27 @@ pub trait Extendable: TableLike<BFieldElement> {
 
     /// Computes the degree bounds of the quotients given the AIR constraints and the interpolant
     /// degree. The AIR constraints are defined over a symbolic ring with `full_width`-many
-    /// variables.
+    /// variables. Constant constraints, including the zero polynomial, are rejected: they do not
+    /// depend on the trace, and the resulting quotient degree bound would be negative.
     fn compute_degree_bounds(
         air_constraints: &[MPolynomial<XFieldElement>],
         interpolant_degree: Degree,
         full_width: usize,
-    ) -> Vec<Degree> {
+    ) -> Result<Vec<Degree>, BaseTableError> {
         air_constraints
             .iter()
-            .map(|mpo| mpo.symbolic_degree_bound(&vec![interpolant_degree; full_width]) - 1)
+            .enumerate()
+            .map(|(constraint_index, mpo)| {
+                let degree_bound =
+                    mpo.symbolic_degree_bound(&vec![interpolant_degree; full_width]) - 1;
+                if mpo.degree() <= 0 || degree_bound < 0 {
+                    return Err(BaseTableError::DegenerateConstraint {
+                        constraint_index,
+                        degree_bound,
+                    });
+                }
+                Ok(degree_bound)
+            })
             .collect()
     }
 
@@ -143,7 +802,7 @@ pub trait Extendable: TableLike<BFieldElement> {
         &self,
         initial_constraints: &[MPolynomial<XFieldElement>],
         interpolant_degree: Degree,
-    ) -> Vec<Degree> {
+    ) -> Result<Vec<Degree>, BaseTableError> {
         let full_width = self.full_width();
         Self::compute_degree_bounds(initial_constraints, interpolant_degree, full_width)
     }
@@ -152,7 +811,7 @@ pub trait Extendable: TableLike<BFieldElement> {
         &self,
         consistency_constraints: &[MPolynomial<XFieldElement>],
         interpolant_degree: Degree,
-    ) -> Vec<Degree> {
+    ) -> Result<Vec<Degree>, BaseTableError> {
         let full_width = self.full_width();
         Self::compute_degree_bounds(consistency_constraints, interpolant_degree, full_width)
     }
@@ -161,7 +820,7 @@ pub trait Extendable: TableLike<BFieldElement> {
         &self,
         transition_constraints: &[MPolynomial<XFieldElement>],
         interpolant_degree: Degree,
-    ) -> Vec<Degree> {
+    ) -> Result<Vec<Degree>, BaseTableError> {
         let full_width = self.full_width();
         Self::compute_degree_bounds(transition_constraints, interpolant_degree, 2 * full_width)
     }
@@ -170,7 +829,7 @@ pub trait Extendable: TableLike<BFieldElement> {
         &self,
         terminal_constraints: &[MPolynomial<XFieldElement>],
         interpolant_degree: Degree,
-    ) -> Vec<Degree> {
+    ) -> Result<Vec<Degree>, BaseTableError> {
         let full_width = self.full_width();
         Self::compute_degree_bounds(terminal_constraints, interpolant_degree, full_width)
     }
@@ -184,14 +843,71 @@ pub trait Extendable: TableLike<BFieldElement> {
         transition_constraints: Vec<MPolynomial<XFieldElement>>,
         terminal_constraints: Vec<MPolynomial<XFieldElement>>,
     ) -> Table<XFieldElement> {
-        let bqdb =
-            self.get_initial_quotient_degree_bounds(&initial_constraints, interpolant_degree);
-        let cqdb = self
-            .get_consistency_quotient_degree_bounds(&consistency_constraints, interpolant_degree);
-        let tqdb =
-            self.get_transition_quotient_degree_bounds(&transition_constraints, interpolant_degree);
-        let termqdb =
-            self.get_terminal_quotient_degree_bounds(&terminal_constraints, interpolant_degree);
+        let full_width = self.full_width();
+        if let Some((row_index, row)) = extended_matrix
+            .iter()
+            .find_position(|row| row.len() != full_width)
+        {
+            let err = BaseTableError::UnexpectedRowWidth {
+                row_index,
+                expected_width: full_width,
+                actual_width: row.len(),
+            };
+            panic!(
+                "{}: The extension columns must be appended before extending: {err}",
+                self.name()
+            );
+        }
+
+        for (category, constraints, variable_count) in [
+            (
+                ConstraintCategory::Initial,
+                &initial_constraints,
+                full_width,
+            ),
+            (
+                ConstraintCategory::Consistency,
+                &consistency_constraints,
+                full_width,
+            ),
+            (
+                ConstraintCategory::Transition,
+                &transition_constraints,
+                2 * full_width,
+            ),
+            (
+                ConstraintCategory::Terminal,
+                &terminal_constraints,
+                full_width,
+            ),
+        ] {
+            if let Err(err) = check_constraint_arity(constraints, variable_count) {
+                panic!("{}: {category}s: {err}", self.name());
+            }
+        }
+
+        let expect_bounds = |bounds: Result<Vec<Degree>, BaseTableError>, category| {
+            bounds.unwrap_or_else(|err| panic!("{}: {category}s: {err}", self.name()))
+        };
+        let bqdb = expect_bounds(
+            self.get_initial_quotient_degree_bounds(&initial_constraints, interpolant_degree),
+            ConstraintCategory::Initial,
+        );
+        let cqdb = expect_bounds(
+            self.get_consistency_quotient_degree_bounds(
+                &consistency_constraints,
+                interpolant_degree,
+            ),
+            ConstraintCategory::Consistency,
+        );
+        let tqdb = expect_bounds(
+            self.get_transition_quotient_degree_bounds(&transition_constraints, interpolant_degree),
+            ConstraintCategory::Transition,
+        );
+        let termqdb = expect_bounds(
+            self.get_terminal_quotient_degree_bounds(&terminal_constraints, interpolant_degree),
+            ConstraintCategory::Terminal,
+        );
         let new_table = self.new_from_lifted_matrix(extended_matrix);
         Table {
             initial_constraints: Some(initial_constraints),
@@ -205,27 +921,211 @@ pub trait Extendable: TableLike<BFieldElement> {
             ..new_table
         }
     }
+
+    /// Like `extension`, but the AIR constraints are built by `constraints`, which receives the
+    /// table's full width. Since the constraints and their degree bounds derive from the same
+    /// width, the two cannot disagree.
+    fn extension_with<F>(
+        &self,
+        extended_matrix: Vec<Vec<XFieldElement>>,
+        interpolant_degree: Degree,
+        constraints: F,
+    ) -> Table<XFieldElement>
+    where
+        F: FnOnce(usize) -> ConstraintSets,
+        Self: Sized,
+    {
+        let ConstraintSets {
+            initial,
+            consistency,
+            transition,
+            terminal,
+        } = constraints(self.full_width());
+        self.extension(
+            extended_matrix,
+            interpolant_degree,
+            initial,
+            consistency,
+            transition,
+            terminal,
+        )
+    }
+}
+
+/// Ensures every constraint is a polynomial in exactly `variable_count` variables, which is the
+/// number of variables `symbolic_degree_bound` and the constraint evaluation assume.
+fn check_constraint_arity(
+    constraints: &[MPolynomial<XFieldElement>],
+    variable_count: usize,
+) -> Result<(), BaseTableError> {
+    match constraints
+        .iter()
+        .position(|constraint| constraint.variable_count != variable_count)
+    {
+        Some(constraint_index) => Err(BaseTableError::UnexpectedConstraintArity {
+            constraint_index,
+            expected_variable_count: variable_count,
+            actual_variable_count: constraints[constraint_index].variable_count,
+        }),
+        None => Ok(()),
+    }
 }
 
-fn disjoint_domain<DataPF: FiniteField>(
+/// Like `disjoint_domain_from`, searching from `1` onwards.
+#[cfg(test)]
+fn disjoint_domain<DataPF: FiniteField + Hash>(
     domain_length: usize,
     disjoint_domain: &[DataPF],
+    fri_domain: &FriDomain<DataPF>,
 ) -> Vec<DataPF> {
-    let mut domain = Vec::with_capacity(domain_length);
-    let mut elm = DataPF::one();
-    while domain.len() != domain_length {
-        if !disjoint_domain.contains(&elm) {
-            domain.push(elm);
-        }
-        elm += DataPF::one();
-    }
+    disjoint_domain_from(DataPF::one(), domain_length, disjoint_domain, fri_domain)
+}
+
+/// The first `domain_length` elements of `start, start + 1, start + 2, …` that are neither in
+/// `disjoint_domain` nor in the `fri_domain`. Since every excluded element rules out at most one
+/// candidate, the first `domain_length + disjoint_domain.len() + fri_domain.length` candidates
+/// always suffice.
+fn disjoint_domain_from<DataPF: FiniteField + Hash>(
+    start: DataPF,
+    domain_length: usize,
+    disjoint_domain: &[DataPF],
+    fri_domain: &FriDomain<DataPF>,
+) -> Vec<DataPF> {
+    let excluded: HashSet<DataPF> = disjoint_domain.iter().copied().collect();
+    let domain = successors(Some(start), |&elm| Some(elm + DataPF::one()))
+        .take(domain_length + excluded.len() + fri_domain.length)
+        .filter(|elm| !excluded.contains(elm) && !fri_domain.contains(elm))
+        .take(domain_length)
+        .collect_vec();
+    assert_eq!(
+        domain_length,
+        domain.len(),
+        "Could not find {domain_length} elements disjoint from the {} excluded elements and the \
+        FRI domain",
+        excluded.len()
+    );
     domain
 }
 
+/// The points a table's columns are interpolated on: the omicron domain, followed by the points
+/// for the trace randomizers. The latter avoid the FRI domain, where a randomizer would be
+/// indistinguishable from an evaluation of the interpolant.
+fn interpolation_domain<DataPF: FiniteField + Hash>(
+    omicron: DataPF,
+    padded_height: usize,
+    num_trace_randomizers: usize,
+    fri_domain: &FriDomain<DataPF>,
+) -> Vec<DataPF> {
+    let omicron_domain = omicron_domain(omicron, padded_height);
+    let randomizer_domain = disjoint_domain_from(
+        randomizer_domain_start(fri_domain),
+        num_trace_randomizers,
+        &omicron_domain,
+        fri_domain,
+    );
+    let interpolation_domain = vec![omicron_domain, randomizer_domain].concat();
+    debug_assert_eq!(
+        randomized_trace_length(padded_height, num_trace_randomizers),
+        interpolation_domain.len()
+    );
+    interpolation_domain
+}
+
+/// Where to start searching for the trace randomizers' points. Both the omicron domain, which
+/// contains `1`, and the FRI domain, which contains its `offset`, meet the sequence `1, 2, 3, …`
+/// at its start if the offset is small. Starting right after the offset skips these collisions;
+/// `disjoint_domain_from` still excludes any remaining ones.
+fn randomizer_domain_start<DataPF: FiniteField>(fri_domain: &FriDomain<DataPF>) -> DataPF {
+    fri_domain.offset + DataPF::one()
+}
+
+/// The length of a column once its trace randomizers are appended, which is also the size of the
+/// domain the column is interpolated on.
+pub fn randomized_trace_length(padded_height: usize, num_trace_randomizers: usize) -> usize {
+    padded_height + num_trace_randomizers
+}
+
+/// Whether the columns can be interpolated with an inverse NTT instead of `fast_interpolate`.
+/// Without trace randomizers, the interpolation domain is the subgroup generated by `omicron`,
+/// which must have order `padded_height`, a power of two of at least 2.
+fn is_ntt_interpolable<DataPF: FiniteField>(
+    omicron: DataPF,
+    padded_height: usize,
+    num_trace_randomizers: usize,
+) -> bool {
+    if num_trace_randomizers != 0 || padded_height < 2 || !padded_height.is_power_of_two() {
+        return false;
+    }
+    let order = padded_height as u32;
+    omicron.mod_pow_u32(order).is_one() && !omicron.mod_pow_u32(order / 2).is_one()
+}
+
+/// The first `padded_height` powers of `omicron`, i.e., `[1, ο, ο², …]`.
+fn omicron_domain<DataPF: FiniteField>(omicron: DataPF, padded_height: usize) -> Vec<DataPF> {
+    successors(Some(DataPF::one()), |&power| Some(power * omicron))
+        .take(padded_height)
+        .collect_vec()
+}
+
+/// How a single trace element propagates through the low-degree extension of its column. See
+/// `TableLike::trace_element`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementTrace<DataPF: FiniteField> {
+    pub value: DataPF,
+
+    /// The point the element is interpolated at, i.e., `omicron^row`.
+    pub omicron_domain_point: DataPF,
+
+    /// The element's summand of the column interpolant, i.e., `value·L_row`, where `L_row` is the
+    /// Lagrange basis polynomial of the element's point over the interpolation domain.
+    pub interpolant_contribution: Polynomial<DataPF>,
+
+    /// The element's summand of every codeword value, i.e., the interpolant contribution evaluated
+    /// on the FRI domain. Every codeword index with a non-zero summand is influenced by the element.
+    pub codeword_contribution: Vec<DataPF>,
+
+    /// The FRI domain index at the same relative position as the element's row.
+    pub codeword_index: usize,
+}
+
+/// Evaluate the interpolant of `codeword` over `fri_domain` in `point` using the barycentric
+/// formula, without interpolating the codeword first. For the coset `{o·ω^i}` of length `n`, the
+/// interpolant evaluates to `(X^n - o^n) / (n·o^n) · Σ_i codeword[i]·x_i / (X - x_i)`.
+pub fn barycentric_evaluate(
+    codeword: &[XFieldElement],
+    fri_domain: &FriDomain<XFieldElement>,
+    point: XFieldElement,
+) -> XFieldElement {
+    assert_eq!(
+        fri_domain.length,
+        codeword.len(),
+        "Codeword length must match length of FRI domain"
+    );
+    let domain_values = fri_domain.domain_values();
+    if let Some(idx) = domain_values.iter().position(|&x| x == point) {
+        return codeword[idx];
+    }
+
+    let differences = domain_values.iter().map(|&x| point - x).collect();
+    let difference_inverses = XFieldElement::batch_inversion(differences);
+    let weighted_sum: XFieldElement = codeword
+        .iter()
+        .zip_eq(domain_values.iter())
+        .zip_eq(difference_inverses.iter())
+        .map(|((&value, &x), &difference_inverse)| value * x * difference_inverse)
+        .sum();
+
+    let length = fri_domain.length as u32;
+    let offset_to_the_length = fri_domain.offset.mod_pow_u32(length);
+    let zerofier = point.mod_pow_u32(length) - offset_to_the_length;
+    let normalizer = BFieldElement::new(length as u64).lift() * offset_to_the_length;
+    zerofier * normalizer.inverse() * weighted_sum
+}
+
 pub trait TableLike<DataPF>: InheritsFromTable<DataPF>
 where
     // Self: Sized,
-    DataPF: FiniteField + GetRandomElements,
+    DataPF: FiniteField + GetRandomElements + Hash,
 {
     // Generic functions common to all tables
 
@@ -233,6 +1133,26 @@ where
         self.inherited_table().name.clone()
     }
 
+    /// Whether the table's height is exactly `padded_height`, i.e., whether it can be
+    /// interpolated. Allows checking inputs before starting an expensive computation.
+    fn is_padded_to(&self, padded_height: usize) -> bool {
+        self.num_rows() == padded_height
+    }
+
+    /// Like `is_padded_to`, but reports the table's name and actual height on failure.
+    fn ensure_padded(&self, padded_height: usize) -> Result<(), BaseTableError> {
+        if !self.is_padded_to(padded_height) {
+            return Err(BaseTableError::NotPadded {
+                name: self.name(),
+                expected: padded_height,
+                got: self.num_rows(),
+            });
+        }
+        Ok(())
+    }
+
+    /// The codewords of the given `columns` on the FRI domain. Fails if the table is not padded
+    /// to `padded_height` or if a column is out of bounds. See `interpolate_columns`.
     fn low_degree_extension(
         &self,
         fri_domain: &FriDomain<DataPF>,
@@ -240,88 +1160,524 @@ where
         padded_height: usize,
         num_trace_randomizers: usize,
         columns: Range<usize>,
-    ) -> Vec<Vec<DataPF>> {
-        // FIXME: Table<> supports Vec<[DataPF; WIDTH]>, but FriDomain does not (yet).
-        self.interpolate_columns(
+    ) -> Result<Vec<Vec<DataPF>>, BaseTableError> {
+        self.low_degree_extension_with_rng(
             fri_domain,
             omicron,
             padded_height,
             num_trace_randomizers,
             columns,
+            &mut rand::thread_rng(),
         )
-        .par_iter()
-        .map(|polynomial| fri_domain.evaluate(polynomial))
-        .collect()
     }
 
-    /// Return the interpolation of columns. The `column_indices` variable
-    /// must be called with *all* the column indices for this particular table,
-    /// if it is called with a subset, it *will* fail.
-    fn interpolate_columns(
+    /// Like `low_degree_extension`, but panics instead of returning an error. For call sites that
+    /// have already padded the table and know its width.
+    fn low_degree_extension_or_panic(
         &self,
         fri_domain: &FriDomain<DataPF>,
         omicron: DataPF,
         padded_height: usize,
         num_trace_randomizers: usize,
         columns: Range<usize>,
-    ) -> Vec<Polynomial<DataPF>> {
-        // FIXME: Inject `rng` instead.
-        let mut rng = rand::thread_rng();
-
-        // Ensure that `matrix` is set and padded before running this function
-        assert_eq!(
+    ) -> Vec<Vec<DataPF>> {
+        self.low_degree_extension(
+            fri_domain,
+            omicron,
             padded_height,
-            self.data().len(),
-            "{}: Table data must be padded before interpolation",
-            self.name()
+            num_trace_randomizers,
+            columns,
+        )
+        .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Like `low_degree_extension`, but samples the trace randomizers from `rng`. Given the same
+    /// `rng` state, the resulting codewords are identical.
+    fn low_degree_extension_with_rng(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: Range<usize>,
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<Vec<DataPF>>, BaseTableError> {
+        // Revealing codeword values on points of the trace domain would leak trace elements.
+        debug_assert!(
+            omicron_domain(omicron, padded_height)
+                .iter()
+                .all(|point| !fri_domain.contains(point)),
+            "{}: The FRI domain with offset {} must be disjoint from the trace domain",
+            self.name(),
+            fri_domain.offset
         );
+        // FIXME: Table<> supports Vec<[DataPF; WIDTH]>, but FriDomain does not (yet).
+        let interpolants = self.interpolate_columns_with_rng(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            columns,
+            rng,
+        )?;
+        Ok(fri_domain.evaluate_batch(&interpolants))
+    }
 
-        if padded_height == 0 {
-            return vec![Polynomial::zero(); columns.len()];
-        }
+    /// Like `low_degree_extension`, but returns one row per FRI domain index instead of one
+    /// codeword per column, i.e., `rows[i][j] == low_degree_extension[j][i]`. This is the layout
+    /// needed for hashing rows into Merkle leaves, and the single entry point for row-major
+    /// low-degree extensions. The codewords are still computed per column and then transposed.
+    fn low_degree_extension_by_row(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: Range<usize>,
+    ) -> Result<Vec<Vec<DataPF>>, BaseTableError> {
+        let codewords = self.low_degree_extension(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            columns,
+        )?;
+        let rows = (0..fri_domain.length)
+            .into_par_iter()
+            .map(|row_idx| codewords.iter().map(|codeword| codeword[row_idx]).collect())
+            .collect();
+        Ok(rows)
+    }
 
-        // FIXME: Unfold with multiplication instead of mapping with power.
-        let omicron_domain = (0..padded_height)
-            .map(|i| omicron.mod_pow_u32(i as u32))
-            .collect_vec();
+    /// The rows of the low-degree extension, i.e., the Merkle leaves. See
+    /// `low_degree_extension_by_row`.
+    fn codeword_rows(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: Range<usize>,
+    ) -> Result<Vec<Vec<DataPF>>, BaseTableError> {
+        self.low_degree_extension_by_row(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            columns,
+        )
+    }
+
+    /// The low-degree extension of every column of the table, in the row-major layout of
+    /// `low_degree_extension_by_row`. Each column is interpolated exactly once, but the codewords
+    /// are transposed afterwards like in `low_degree_extension_by_row`.
+    fn low_degree_extend_all(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+    ) -> Vec<Vec<DataPF>> {
+        let width = self.data().first().map_or(0, |row| row.len());
+        self.low_degree_extension_by_row(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            0..width,
+        )
+        .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Follow the element in `row` and `col` through the low-degree extension. Intended for
+    /// debugging. The padded table is interpolated on the same domain as in `interpolate_columns`,
+    /// but the trace randomizers' contributions are not part of the returned trace.
+    fn trace_element(
+        &self,
+        row: usize,
+        col: usize,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        num_trace_randomizers: usize,
+    ) -> ElementTrace<DataPF> {
+        let padded_height = self.data().len();
+        assert!(
+            row < padded_height,
+            "{}: row {row} is out of bounds for table of height {padded_height}",
+            self.name()
+        );
+        let value = self.data()[row][col];
 
-        let num_trace_randomizers = num_trace_randomizers;
-        let randomizer_domain = disjoint_domain(num_trace_randomizers, &omicron_domain);
+        let interpolation_domain =
+            interpolation_domain(omicron, padded_height, num_trace_randomizers, fri_domain);
+        let omicron_domain_point = interpolation_domain[row];
+        let other_points = [
+            &interpolation_domain[..row],
+            &interpolation_domain[row + 1..],
+        ]
+        .concat();
+        let vanishing_elsewhere = Polynomial::zerofier(&other_points);
+        let normalizer = vanishing_elsewhere.evaluate(&omicron_domain_point);
+        let interpolant_contribution = vanishing_elsewhere.scalar_mul(value / normalizer);
+        let codeword_contribution = fri_domain.evaluate(&interpolant_contribution);
 
-        let interpolation_domain = vec![omicron_domain, randomizer_domain].concat();
-        let mut all_randomized_traces = vec![];
-        let data = self.data();
+        ElementTrace {
+            value,
+            omicron_domain_point,
+            interpolant_contribution,
+            codeword_contribution,
+            codeword_index: row * fri_domain.length / padded_height,
+        }
+    }
 
-        for col in columns {
-            let trace = data.iter().map(|row| row[col]).collect();
-            let randomizers = DataPF::random_elements(num_trace_randomizers, &mut rng);
-            let randomized_trace = vec![trace, randomizers].concat();
+    /// The degree of every column's interpolant, as computed by `interpolate_columns`. Helps to
+    /// find the column violating a degree bound. The zero polynomial has degree -1.
+    fn column_degrees(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+    ) -> Vec<Degree> {
+        let width = self.data().first().map_or(0, |row| row.len());
+        self.interpolate_columns_or_panic(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            0..width,
+        )
+        .iter()
+        .map(|interpolant| interpolant.degree() as Degree)
+        .collect()
+    }
+
+    /// Return the interpolation of the given `columns`. Any subset of the table's columns can be
+    /// interpolated, since the interpolation domain does not depend on the columns. See
+    /// `interpolate_selected_columns` for columns that are not contiguous.
+    ///
+    /// Without trace randomizers, the columns are interpolated over the omicron domain only. The
+    /// interpolants are then deterministic and of degree at most `padded_height - 1`, which is
+    /// useful for comparing against a hand computation.
+    ///
+    /// Fails if the table is not padded to `padded_height` or if a column is out of bounds.
+    fn interpolate_columns(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: Range<usize>,
+    ) -> Result<Vec<Polynomial<DataPF>>, BaseTableError> {
+        self.interpolate_columns_with_rng(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            columns,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like `interpolate_columns`, but panics instead of returning an error. For call sites that
+    /// have already padded the table and know its width.
+    fn interpolate_columns_or_panic(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: Range<usize>,
+    ) -> Vec<Polynomial<DataPF>> {
+        self.interpolate_columns(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            columns,
+        )
+        .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Like `interpolate_columns`, for the `base_columns`.
+    fn interpolate_base_columns(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+    ) -> Result<Vec<Polynomial<DataPF>>, BaseTableError> {
+        self.interpolate_columns(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            self.base_columns(),
+        )
+    }
+
+    /// Like `interpolate_columns`, for the `extension_columns`.
+    fn interpolate_extension_columns(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+    ) -> Result<Vec<Polynomial<DataPF>>, BaseTableError> {
+        self.interpolate_columns(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            self.extension_columns(),
+        )
+    }
+
+    /// Like `interpolate_columns`, but stores the interpolants in the table. A subsequent call with
+    /// the same parameters, including the FRI domain's offset and length, returns the stored
+    /// interpolants, including their trace randomizers, instead of interpolating again. Use
+    /// `clear_interpolant_cache` to free the memory.
+    fn interpolate_columns_cached(
+        &mut self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: Range<usize>,
+    ) -> Vec<Polynomial<DataPF>> {
+        let is_cache_hit = |cached: &&CachedInterpolants<DataPF>| {
+            cached.fri_domain_offset == fri_domain.offset
+                && cached.fri_domain_length == fri_domain.length
+                && cached.omicron == omicron
+                && cached.padded_height == padded_height
+                && cached.num_trace_randomizers == num_trace_randomizers
+                && cached.columns == columns
+        };
+        if let Some(cached) = self
+            .inherited_table()
+            .interpolant_cache
+            .iter()
+            .find(is_cache_hit)
+        {
+            return cached.interpolants.clone();
+        }
+
+        let interpolants = self.interpolate_columns_or_panic(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            columns.clone(),
+        );
+        self.mut_inherited_table()
+            .interpolant_cache
+            .push(CachedInterpolants {
+                fri_domain_offset: fri_domain.offset,
+                fri_domain_length: fri_domain.length,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                columns,
+                interpolants: interpolants.clone(),
+            });
+        interpolants
+    }
+
+    /// Like `interpolate_columns`, but samples the trace randomizers from `rng`. Given the same
+    /// `rng` state, the resulting interpolants are identical.
+    fn interpolate_columns_with_rng(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: Range<usize>,
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<Polynomial<DataPF>>, BaseTableError> {
+        self.interpolate_selected_columns_with_rng(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            &columns.collect_vec(),
+            rng,
+        )
+    }
+
+    /// Like `interpolate_columns`, but for an arbitrary selection of `columns`, which need not be
+    /// contiguous. The interpolants are returned in the order of `columns`.
+    fn interpolate_selected_columns(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: &[usize],
+    ) -> Result<Vec<Polynomial<DataPF>>, BaseTableError> {
+        self.interpolate_selected_columns_with_rng(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            columns,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like `interpolate_selected_columns`, but samples the trace randomizers from `rng`.
+    fn interpolate_selected_columns_with_rng(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: &[usize],
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<Polynomial<DataPF>>, BaseTableError> {
+        self.interpolate_selected_columns_in_chunks(
+            fri_domain,
+            omicron,
+            padded_height,
+            num_trace_randomizers,
+            columns,
+            None,
+            rng,
+        )
+    }
+
+    /// Like `interpolate_selected_columns_with_rng`, but every parallel task interpolates a chunk
+    /// of `chunk_size` many columns. Without a `chunk_size`, every column is its own task. The
+    /// interpolants do not depend on the `chunk_size`.
+    #[allow(clippy::too_many_arguments)]
+    fn interpolate_selected_columns_in_chunks(
+        &self,
+        fri_domain: &FriDomain<DataPF>,
+        omicron: DataPF,
+        padded_height: usize,
+        num_trace_randomizers: usize,
+        columns: &[usize],
+        chunk_size: Option<usize>,
+        mut rng: &mut dyn RngCore,
+    ) -> Result<Vec<Polynomial<DataPF>>, BaseTableError> {
+        // Ensure that `matrix` is set and padded before running this function
+        self.ensure_padded(padded_height)?;
+
+        if padded_height == 0 {
+            return Ok(vec![Polynomial::zero(); columns.len()]);
+        }
+
+        let width = self.data()[0].len();
+        if let Some(&column) = columns.iter().find(|&&column| column >= width) {
+            return Err(BaseTableError::ColumnOutOfBounds {
+                name: self.name(),
+                column,
+                width,
+            });
+        }
+
+        let chunk_size = chunk_size.unwrap_or(1).max(1);
+
+        if is_ntt_interpolable(omicron, padded_height, num_trace_randomizers) {
+            let log_2_of_n = padded_height.trailing_zeros();
+            let mut traces = columns
+                .iter()
+                .map(|&col| self.column_to_vec(col))
+                .collect_vec();
+            traces.par_chunks_mut(chunk_size).for_each(|chunk| {
+                for trace in chunk.iter_mut() {
+                    intt(trace, omicron, log_2_of_n);
+                }
+            });
+            return Ok(traces.into_iter().map(Polynomial::new).collect());
+        }
+
+        let interpolation_domain =
+            interpolation_domain(omicron, padded_height, num_trace_randomizers, fri_domain);
+        debug_assert!(
+            interpolation_domain[padded_height..]
+                .iter()
+                .all(|point| !fri_domain.contains(point)),
+            "{}: Trace randomizers must not be interpolated on points of the FRI domain",
+            self.name()
+        );
+        let trace_length = randomized_trace_length(padded_height, num_trace_randomizers);
+        let mut all_randomized_traces = Vec::with_capacity(columns.len());
+
+        for &col in columns {
+            let mut randomized_trace = Vec::with_capacity(trace_length);
+            randomized_trace.extend(self.column(col).copied());
+            randomized_trace.extend(DataPF::random_elements(num_trace_randomizers, &mut rng));
             assert_eq!(
+                trace_length,
                 randomized_trace.len(),
-                interpolation_domain.len(),
                 "Length of x values and y values must match"
             );
             all_randomized_traces.push(randomized_trace);
         }
 
-        all_randomized_traces
-            .par_iter()
-            .map(|randomized_trace| {
-                Polynomial::fast_interpolate(
-                    &interpolation_domain,
-                    randomized_trace,
-                    &fri_domain.omega,
-                    fri_domain.length,
-                )
+        let interpolants = all_randomized_traces
+            .par_chunks(chunk_size)
+            .flat_map_iter(|chunk| {
+                chunk.iter().map(|randomized_trace| {
+                    Polynomial::fast_interpolate(
+                        &interpolation_domain,
+                        randomized_trace,
+                        &fri_domain.omega,
+                        fri_domain.length,
+                    )
+                })
             })
-            .collect()
+            .collect();
+        Ok(interpolants)
     }
 }
 
 #[cfg(test)]
 mod test_base_table {
-    use crate::table::base_table::disjoint_domain;
+    use itertools::Itertools;
+    use num_traits::{One, Zero};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::fri_domain::{lift_domain, FriDomain};
+    #[cfg(feature = "debug-export")]
+    use crate::table::base_table::CsvField;
+    use crate::table::base_table::{
+        barycentric_evaluate, check_constraint_arity, disjoint_domain, disjoint_domain_from,
+        interpolation_domain, is_ntt_interpolable, omicron_domain, randomized_trace_length,
+        randomizer_domain_start, BaseTableError, ConstraintCategory, ConstraintSets, Extendable,
+        InheritsFromTable, Table, TableLike,
+    };
+    use crate::table::hash_table::{self, HashTable};
+    use crate::table::program_table::{ExtProgramTable, ProgramTable};
+    use crate::table::table_collection::derive_omicron;
     use twenty_first::shared_math::b_field_element::BFieldElement;
+    use twenty_first::shared_math::mpolynomial::{Degree, MPolynomial};
+    use twenty_first::shared_math::ntt::{intt, ntt};
+    use twenty_first::shared_math::polynomial::Polynomial;
+    use twenty_first::shared_math::traits::{GetRandomElements, ModPowU32, PrimitiveRootOfUnity};
+    use twenty_first::shared_math::x_field_element::XFieldElement;
+
+    fn dummy_program_table(height: u64) -> ProgramTable {
+        let matrix = (0..height)
+            .map(|i| {
+                vec![
+                    BFieldElement::new(i),
+                    BFieldElement::new(3 * i + 1),
+                    BFieldElement::new(0),
+                ]
+            })
+            .collect();
+        ProgramTable::new_prover(matrix)
+    }
+
+    fn dummy_fri_domain(length: usize) -> FriDomain<BFieldElement> {
+        FriDomain {
+            offset: BFieldElement::generator(),
+            omega: BFieldElement::primitive_root_of_unity(length as u64).unwrap(),
+            length,
+        }
+    }
 
     #[test]
     fn disjoint_domain_test() {
@@ -330,9 +1686,1458 @@ mod test_base_table {
             BFieldElement::new(5),
             BFieldElement::new(4),
         ];
-        let ddomain = disjoint_domain(5, &domain);
-        for d in ddomain {
-            assert!(!domain.contains(&d));
+        let fri_domain = dummy_fri_domain(8);
+        assert!(fri_domain.contains(&BFieldElement::new(7)));
+
+        let ddomain = disjoint_domain(5, &domain, &fri_domain);
+        for d in ddomain.iter() {
+            assert!(!domain.contains(d));
+            assert!(!fri_domain.contains(d));
+        }
+        let expected = [1, 3, 6, 8, 9].map(BFieldElement::new).to_vec();
+        assert_eq!(expected, ddomain);
+
+        assert!(disjoint_domain(0, &domain, &fri_domain).is_empty());
+        let large_domain = (1..=1000).map(BFieldElement::new).collect_vec();
+        assert_eq!(
+            vec![BFieldElement::new(1001)],
+            disjoint_domain(1, &large_domain, &fri_domain)
+        );
+    }
+
+    #[test]
+    fn disjoint_domain_from_test() {
+        let fri_domain = dummy_fri_domain(8);
+        let offset = fri_domain.offset;
+        let excluded = [
+            BFieldElement::new(3),
+            offset,
+            offset + BFieldElement::new(2),
+        ];
+
+        let ddomain = disjoint_domain_from(BFieldElement::new(1), 10, &excluded, &fri_domain);
+        assert_eq!(10, ddomain.len());
+        for d in ddomain.iter() {
+            assert!(!excluded.contains(d));
+            assert!(!fri_domain.contains(d));
+        }
+        assert_eq!(
+            disjoint_domain(10, &excluded, &fri_domain),
+            disjoint_domain_from(BFieldElement::one(), 10, &excluded, &fri_domain)
+        );
+
+        let start = BFieldElement::new(100);
+        let ddomain = disjoint_domain_from(start, 3, &excluded, &fri_domain);
+        assert_eq!([100, 101, 102].map(BFieldElement::new).to_vec(), ddomain);
+
+        // The trace randomizers' points start right after the FRI domain's offset.
+        let padded_height = 8;
+        let omicron = derive_omicron(padded_height as u64);
+        let domain = interpolation_domain(omicron, padded_height, 4, &fri_domain);
+        assert_eq!(offset + BFieldElement::one(), domain[padded_height]);
+        for point in domain[padded_height..].iter() {
+            assert!(!fri_domain.contains(point));
+            assert!(!domain[..padded_height].contains(point));
+        }
+    }
+
+    #[test]
+    fn randomized_trace_length_test() {
+        let fri_domain = dummy_fri_domain(64);
+        for (padded_height, num_trace_randomizers) in [(0, 0), (1, 0), (4, 2), (8, 0), (16, 20)] {
+            let omicron = derive_omicron(padded_height as u64);
+            let omicron_domain = omicron_domain(omicron, padded_height);
+            let randomizer_domain = disjoint_domain_from(
+                randomizer_domain_start(&fri_domain),
+                num_trace_randomizers,
+                &omicron_domain,
+                &fri_domain,
+            );
+            let expected_length = omicron_domain.len() + randomizer_domain.len();
+            assert_eq!(
+                expected_length,
+                randomized_trace_length(padded_height, num_trace_randomizers)
+            );
+            assert_eq!(
+                expected_length,
+                interpolation_domain(omicron, padded_height, num_trace_randomizers, &fri_domain)
+                    .len()
+            );
+        }
+    }
+
+    #[test]
+    fn omicron_domain_test() {
+        for padded_height in [0, 1, 2, 8, 1024] {
+            let omicron =
+                BFieldElement::primitive_root_of_unity(padded_height.max(1) as u64).unwrap();
+            let expected = (0..padded_height)
+                .map(|i| omicron.mod_pow_u32(i as u32))
+                .collect_vec();
+            assert_eq!(expected, omicron_domain(omicron, padded_height));
+        }
+    }
+
+    #[test]
+    fn lifted_table_interpolates_over_padded_height_and_randomizers_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 4;
+        let table = dummy_program_table(padded_height as u64);
+        let lifted_matrix = table
+            .data()
+            .iter()
+            .map(|row| row.iter().map(|bfe| bfe.lift()).collect_vec())
+            .collect_vec();
+        let mut ext_table = ExtProgramTable::default();
+        *ext_table.mut_inherited_table() = table.new_from_lifted_matrix(lifted_matrix);
+
+        let fri_domain = lift_domain(&dummy_fri_domain(32));
+        let omicron = derive_omicron::<XFieldElement>(padded_height as u64);
+        let interpolants = ext_table
+            .interpolate_columns(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                ext_table.base_columns(),
+            )
+            .unwrap();
+
+        // The interpolation domain has `padded_height + num_trace_randomizers` points, and the
+        // randomizer values make the interpolants use the full degree with high probability.
+        let expected_degree = (padded_height + num_trace_randomizers - 1) as isize;
+        for (col, interpolant) in interpolants.iter().enumerate() {
+            assert_eq!(expected_degree, interpolant.degree());
+            for (row, point) in omicron_domain(omicron, padded_height).iter().enumerate() {
+                assert_eq!(ext_table.data()[row][col], interpolant.evaluate(point));
+            }
+        }
+    }
+
+    #[test]
+    fn ntt_interpolation_agrees_with_fast_interpolate_test() {
+        let fri_domain = dummy_fri_domain(64);
+        for padded_height in [2, 4, 16] {
+            let table = dummy_program_table(padded_height as u64);
+            let omicron = derive_omicron(padded_height as u64);
+            assert!(is_ntt_interpolable(omicron, padded_height, 0));
+
+            let interpolants = table
+                .interpolate_columns(&fri_domain, omicron, padded_height, 0, 0..3)
+                .unwrap();
+            let omicron_domain = omicron_domain(omicron, padded_height);
+            for (col, interpolant) in interpolants.iter().enumerate() {
+                let expected = Polynomial::fast_interpolate(
+                    &omicron_domain,
+                    &table.column_to_vec(col),
+                    &fri_domain.omega,
+                    fri_domain.length,
+                );
+                assert_eq!(&expected, interpolant);
+            }
+        }
+
+        let omicron: BFieldElement = derive_omicron(8);
+        assert!(!is_ntt_interpolable(omicron, 8, 1));
+        assert!(!is_ntt_interpolable(omicron, 4, 0));
+        assert!(!is_ntt_interpolable(omicron * omicron, 8, 0));
+        assert!(!is_ntt_interpolable(BFieldElement::one(), 1, 0));
+    }
+
+    #[test]
+    fn chunked_interpolation_agrees_with_per_column_interpolation_test() {
+        let fri_domain = dummy_fri_domain(64);
+        let padded_height = 16;
+        let table = dummy_program_table(padded_height as u64);
+        let omicron = derive_omicron(padded_height as u64);
+        let columns = [2, 0, 1, 2, 1];
+
+        for num_trace_randomizers in [0, 4] {
+            let interpolate = |chunk_size| {
+                table
+                    .interpolate_selected_columns_in_chunks(
+                        &fri_domain,
+                        omicron,
+                        padded_height,
+                        num_trace_randomizers,
+                        &columns,
+                        chunk_size,
+                        &mut StdRng::seed_from_u64(5),
+                    )
+                    .unwrap()
+            };
+            let per_column = interpolate(None);
+            assert_eq!(columns.len(), per_column.len());
+            for chunk_size in [Some(1), Some(2), Some(3), Some(columns.len()), Some(100)] {
+                assert_eq!(per_column, interpolate(chunk_size), "{chunk_size:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn from_columns_test() {
+        let columns = (0..3)
+            .map(|col| {
+                (0..5)
+                    .map(|row| BFieldElement::new(10 * row + col))
+                    .collect_vec()
+            })
+            .collect_vec();
+        let mut table = dummy_program_table(0);
+        *table.mut_inherited_table() =
+            Table::from_columns(3, 4, columns.clone(), "Columns".to_string()).unwrap();
+        assert_eq!((5, 3), table.shape());
+        for (col, column) in columns.iter().enumerate() {
+            assert_eq!(column, &table.column_to_vec(col));
+        }
+        assert_eq!(
+            vec![
+                BFieldElement::new(20),
+                BFieldElement::new(21),
+                BFieldElement::new(22)
+            ],
+            table.get_row(2)
+        );
+
+        let mut ragged_columns = columns;
+        ragged_columns[1].pop();
+        let err = Table::from_columns(3, 4, ragged_columns, "Ragged".to_string()).unwrap_err();
+        assert_eq!(
+            BaseTableError::RaggedColumn {
+                column_index: 1,
+                expected_height: 5,
+                actual_height: 4,
+            },
+            err
+        );
+
+        let no_columns: Vec<Vec<BFieldElement>> = vec![];
+        let empty_table = Table::from_columns(0, 0, no_columns, "Empty".to_string()).unwrap();
+        assert!(empty_table.matrix.is_empty());
+    }
+
+    #[test]
+    fn push_row_test() {
+        let mut table = dummy_program_table(2);
+        let row = vec![
+            BFieldElement::new(2),
+            BFieldElement::new(7),
+            BFieldElement::new(0),
+        ];
+        assert_eq!(Ok(()), table.push_row(row.clone()));
+        assert_eq!((3, 3), table.shape());
+        assert_eq!(row.as_slice(), table.get_row(2));
+
+        let too_wide_row = vec![BFieldElement::new(1); 4];
+        assert_eq!(
+            Err(BaseTableError::UnexpectedRowWidth {
+                row_index: 3,
+                expected_width: 3,
+                actual_width: 4,
+            }),
+            table.push_row(too_wide_row)
+        );
+        assert_eq!((3, 3), table.shape());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be disjoint from the trace domain")]
+    #[cfg(debug_assertions)]
+    fn low_degree_extension_with_offset_in_trace_domain_panics_test() {
+        let padded_height = 8;
+        let table = dummy_program_table(padded_height as u64);
+        let omicron: BFieldElement = derive_omicron(padded_height as u64);
+        let fri_domain = FriDomain {
+            offset: omicron,
+            ..dummy_fri_domain(32)
+        };
+        let _ = table.low_degree_extension(&fri_domain, omicron, padded_height, 0, 0..3);
+    }
+
+    #[test]
+    fn shape_test() {
+        let table = dummy_program_table(5);
+        assert_eq!((5, 3), table.shape());
+
+        let empty_table = dummy_program_table(0);
+        assert_eq!((0, empty_table.base_width()), empty_table.shape());
+
+        let mut ext_table = ExtProgramTable::default();
+        *ext_table.mut_inherited_table() =
+            empty_table.extension_with(vec![], 3, |_| ConstraintSets::default());
+        assert_eq!((0, ext_table.full_width()), ext_table.shape());
+    }
+
+    #[test]
+    fn lifted_matrix_with_test() {
+        let table = dummy_program_table(4);
+        let extension_rows = (0..4)
+            .map(|row| vec![XFieldElement::new_const(BFieldElement::new(100 + row))])
+            .collect_vec();
+        let lifted_matrix = table.lifted_matrix_with(extension_rows.clone());
+
+        assert_eq!(4, lifted_matrix.len());
+        for ((lifted_row, base_row), extension_row) in lifted_matrix
+            .iter()
+            .zip_eq(table.data())
+            .zip_eq(extension_rows)
+        {
+            let (lifted_base, lifted_extension) = lifted_row.split_at(table.base_width());
+            let embedded_base = base_row
+                .iter()
+                .map(|&bfe| XFieldElement::new_const(bfe))
+                .collect_vec();
+            assert_eq!(embedded_base, lifted_base);
+            assert_eq!(extension_row, lifted_extension);
+        }
+
+        let ext_table = table.extension_with(lifted_matrix, 3, |_| ConstraintSets::default());
+        assert_eq!(
+            table
+                .column_to_vec(1)
+                .into_iter()
+                .map(|bfe| bfe.lift())
+                .collect_vec(),
+            ext_table.matrix.iter().map(|row| row[1]).collect_vec()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Extension row 2 has the wrong width")]
+    fn lifted_matrix_with_too_wide_extension_row_panics_test() {
+        let table = dummy_program_table(4);
+        let mut extension_rows = vec![vec![XFieldElement::zero()]; 4];
+        extension_rows[2].push(XFieldElement::zero());
+        table.lifted_matrix_with(extension_rows);
+    }
+
+    #[test]
+    fn is_padded_to_test() {
+        let mut table = dummy_program_table(5);
+        assert!(table.is_padded_to(5));
+        assert!(!table.is_padded_to(8));
+        assert_eq!(Ok(()), table.ensure_padded(5));
+        assert_eq!(
+            Err(BaseTableError::NotPadded {
+                name: table.name(),
+                expected: 8,
+                got: 5,
+            }),
+            table.ensure_padded(8)
+        );
+
+        table.pad(8);
+        assert!(table.is_padded_to(8));
+        assert_eq!(Ok(()), table.ensure_padded(8));
+    }
+
+    #[test]
+    fn base_and_extension_columns_partition_all_columns_test() {
+        let table = dummy_program_table(4);
+        let base_columns = table.base_columns();
+        let extension_columns = table.extension_columns();
+        assert_eq!(0, base_columns.start);
+        assert_eq!(base_columns.end, extension_columns.start);
+        assert_eq!(table.full_width(), extension_columns.end);
+        assert_eq!(
+            (0..table.full_width()).collect_vec(),
+            base_columns.chain(extension_columns).collect_vec()
+        );
+
+        let padded_height = 4;
+        let fri_domain = dummy_fri_domain(32);
+        let omicron = derive_omicron(padded_height as u64);
+        assert_eq!(
+            table.interpolate_columns(&fri_domain, omicron, padded_height, 0, 0..3),
+            table.interpolate_base_columns(&fri_domain, omicron, padded_height, 0)
+        );
+    }
+
+    #[test]
+    fn randomized_traces_are_trace_followed_by_randomizers_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 3;
+        let table = dummy_program_table(padded_height as u64);
+        let fri_domain = dummy_fri_domain(64);
+        let omicron = derive_omicron(padded_height as u64);
+        let seed = 5;
+
+        let interpolants = table
+            .interpolate_columns_with_rng(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                0..3,
+                &mut StdRng::seed_from_u64(seed),
+            )
+            .unwrap();
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let interpolation_domain =
+            interpolation_domain(omicron, padded_height, num_trace_randomizers, &fri_domain);
+        for (col, interpolant) in interpolants.iter().enumerate() {
+            let trace = table.column_to_vec(col);
+            let randomizers = BFieldElement::random_elements(num_trace_randomizers, &mut rng);
+            let randomized_trace = vec![trace, randomizers].concat();
+            let expected = Polynomial::fast_interpolate(
+                &interpolation_domain,
+                &randomized_trace,
+                &fri_domain.omega,
+                fri_domain.length,
+            );
+            assert_eq!(&expected, interpolant);
+        }
+    }
+
+    #[test]
+    fn codeword_rows_are_transposed_low_degree_extension_test() {
+        let padded_height = 8;
+        let table = dummy_program_table(padded_height as u64);
+        let fri_domain = dummy_fri_domain(32);
+        let omicron = derive_omicron(padded_height as u64);
+        let num_trace_randomizers = 0;
+
+        let codewords = table
+            .low_degree_extension(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                1..3,
+            )
+            .unwrap();
+        let codeword_rows = table
+            .codeword_rows(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                1..3,
+            )
+            .unwrap();
+
+        assert_eq!(fri_domain.length, codeword_rows.len());
+        for (i, row) in codeword_rows.iter().enumerate() {
+            assert_eq!(codewords.len(), row.len());
+            for (j, &element) in row.iter().enumerate() {
+                assert_eq!(codewords[j][i], element);
+            }
+        }
+
+        let unpadded_table = dummy_program_table(padded_height as u64 - 1);
+        assert!(unpadded_table
+            .codeword_rows(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                1..3,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn low_degree_extension_by_row_is_transposed_low_degree_extension_test() {
+        let padded_height = 8;
+        let table = dummy_program_table(padded_height as u64);
+        let fri_domain = dummy_fri_domain(32);
+        let omicron = derive_omicron(padded_height as u64);
+        let num_trace_randomizers = 0;
+
+        let codewords = table
+            .low_degree_extension(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                0..3,
+            )
+            .unwrap();
+        let rows = table
+            .low_degree_extension_by_row(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                0..3,
+            )
+            .unwrap();
+
+        assert_eq!(fri_domain.length, rows.len());
+        for (row_idx, row) in rows.iter().enumerate() {
+            assert_eq!(codewords.len(), row.len());
+            for (col_idx, codeword) in codewords.iter().enumerate() {
+                assert_eq!(codeword[row_idx], row[col_idx]);
+            }
+        }
+
+        // The other row-major entry points delegate to `low_degree_extension_by_row`.
+        let codeword_rows = table
+            .codeword_rows(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                0..3,
+            )
+            .unwrap();
+        assert_eq!(rows, codeword_rows);
+    }
+
+    #[test]
+    fn low_degree_extend_all_covers_every_column_test() {
+        let padded_height = 8;
+        let table = dummy_program_table(padded_height as u64);
+        let fri_domain = dummy_fri_domain(32);
+        let omicron = derive_omicron(padded_height as u64);
+
+        let rows = table.low_degree_extend_all(&fri_domain, omicron, padded_height, 0);
+        let expected_rows = table
+            .low_degree_extension_by_row(&fri_domain, omicron, padded_height, 0, 0..3)
+            .unwrap();
+        assert_eq!(expected_rows, rows);
+
+        let empty_table = dummy_program_table(0);
+        assert!(empty_table
+            .low_degree_extend_all(&fri_domain, omicron, 0, 0)
+            .iter()
+            .all(|row| row.is_empty()));
+    }
+
+    #[test]
+    fn interpolation_errors_test() {
+        let padded_height = 8;
+        let table = dummy_program_table(padded_height as u64);
+        let fri_domain = dummy_fri_domain(32);
+        let omicron = derive_omicron(padded_height as u64);
+
+        let not_padded = table.interpolate_columns(&fri_domain, omicron, 16, 0, 0..3);
+        assert_eq!(
+            Err(BaseTableError::NotPadded {
+                name: table.name(),
+                expected: 16,
+                got: padded_height,
+            }),
+            not_padded
+        );
+        assert!(not_padded
+            .unwrap_err()
+            .to_string()
+            .contains("Table data must be padded before interpolation"));
+
+        let out_of_bounds =
+            table.interpolate_selected_columns(&fri_domain, omicron, padded_height, 0, &[1, 3, 5]);
+        assert_eq!(
+            Err(BaseTableError::ColumnOutOfBounds {
+                name: table.name(),
+                column: 3,
+                width: 3,
+            }),
+            out_of_bounds
+        );
+
+        assert_eq!(
+            Err(BaseTableError::ColumnOutOfBounds {
+                name: table.name(),
+                column: 3,
+                width: 3,
+            }),
+            table.low_degree_extension(&fri_domain, omicron, padded_height, 0, 2..4)
+        );
+        assert!(table
+            .low_degree_extension(&fri_domain, omicron, padded_height, 0, 0..3)
+            .is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Table data must be padded before interpolation")]
+    fn low_degree_extension_or_panic_panics_on_unpadded_table_test() {
+        let table = dummy_program_table(5);
+        let fri_domain = dummy_fri_domain(32);
+        let omicron = derive_omicron(8);
+        table.low_degree_extension_or_panic(&fri_domain, omicron, 8, 0, 0..3);
+    }
+
+    #[test]
+    fn with_data_keeps_name_stable_test() {
+        let table = dummy_program_table(4);
+        let base_name = table.name();
+        let once = table.inherited_table().with_data(table.data().clone());
+        let twice = once.with_data(vec![]);
+        assert_eq!(format!("{base_name} with data"), once.name);
+        assert_eq!(once.name, twice.name);
+
+        let lifted = table.new_from_lifted_matrix(vec![]);
+        assert_eq!(format!("{base_name} with lifted matrix"), lifted.name);
+        assert_eq!(once.name, lifted.with_data(vec![]).name);
+
+        let named = twice.with_data_named(vec![], "Codewords".to_string());
+        assert_eq!("Codewords", named.name);
+        assert_eq!("Codewords with data", named.with_data(vec![]).name);
+    }
+
+    #[test]
+    fn interpolant_cache_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 2;
+        let mut table = dummy_program_table(padded_height as u64);
+        let fri_domain = dummy_fri_domain(32);
+        let omicron = derive_omicron(padded_height as u64);
+
+        let interpolate_cached = |table: &mut ProgramTable, columns| {
+            table.interpolate_columns_cached(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                columns,
+            )
+        };
+        let interpolants = interpolate_cached(&mut table, 0..3);
+        assert_eq!(interpolants, interpolate_cached(&mut table, 0..3));
+        assert_eq!(1, table.inherited_table().interpolant_cache.len());
+        interpolate_cached(&mut table, 0..2);
+        assert_eq!(2, table.inherited_table().interpolant_cache.len());
+
+        // A different FRI domain, be it in offset or in length, is a cache miss.
+        let shifted_fri_domain = FriDomain {
+            offset: fri_domain.offset * fri_domain.offset,
+            ..fri_domain.clone()
+        };
+        let longer_fri_domain = dummy_fri_domain(64);
+        for other_fri_domain in [&shifted_fri_domain, &longer_fri_domain] {
+            table.interpolate_columns_cached(
+                other_fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                0..3,
+            );
+        }
+        assert_eq!(4, table.inherited_table().interpolant_cache.len());
+
+        // The cache does not take part in comparing tables.
+        let table_without_cache = table.inherited_table().with_data(table.data().clone());
+        assert_eq!(
+            Table {
+                name: table.inherited_table().name.clone(),
+                ..table_without_cache
+            },
+            *table.inherited_table()
+        );
+
+        // Cached interpolants agree with a fresh interpolation on the omicron domain; only the
+        // trace randomizers differ.
+        let fresh_interpolants = table
+            .interpolate_columns(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                0..3,
+            )
+            .unwrap();
+        for (cached, fresh) in interpolants.iter().zip_eq(fresh_interpolants.iter()) {
+            for omicron_power in omicron_domain(omicron, padded_height) {
+                assert_eq!(
+                    cached.evaluate(&omicron_power),
+                    fresh.evaluate(&omicron_power)
+                );
+            }
+        }
+
+        // Without trace randomizers, interpolation is deterministic.
+        let deterministic_interpolants =
+            table.interpolate_columns_cached(&fri_domain, omicron, padded_height, 0, 0..3);
+        assert_eq!(
+            table
+                .interpolate_columns(&fri_domain, omicron, padded_height, 0, 0..3)
+                .unwrap(),
+            deterministic_interpolants
+        );
+
+        let table_with_data = table.inherited_table().with_data(table.data().clone());
+        assert!(table_with_data.interpolant_cache.is_empty());
+
+        table.clear_interpolant_cache();
+        assert!(table.inherited_table().interpolant_cache.is_empty());
+        assert_ne!(interpolants, interpolate_cached(&mut table, 0..3));
+
+        table.mut_data()[0][1] = BFieldElement::new(42);
+        assert!(table.inherited_table().interpolant_cache.is_empty());
+    }
+
+    #[test]
+    fn interpolate_selected_columns_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 2;
+        let mut rng = rand::thread_rng();
+        let matrix = (0..padded_height)
+            .map(|_| BFieldElement::random_elements(hash_table::BASE_WIDTH, &mut rng))
+            .collect();
+        let table = HashTable::new_prover(matrix);
+        let fri_domain = dummy_fri_domain(64);
+        let omicron = derive_omicron(padded_height as u64);
+
+        let columns = [0, 3, 5];
+        let interpolants = table
+            .interpolate_selected_columns(
+                &fri_domain,
+                omicron,
+                padded_height,
+                num_trace_randomizers,
+                &columns,
+            )
+            .unwrap();
+        assert_eq!(columns.len(), interpolants.len());
+        for (&col, interpolant) in columns.iter().zip_eq(interpolants.iter()) {
+            assert!(interpolant.degree() < (padded_height + num_trace_randomizers) as isize);
+            for (row_idx, omicron_power) in omicron_domain(omicron, padded_height)
+                .into_iter()
+                .enumerate()
+            {
+                assert_eq!(
+                    table.data()[row_idx][col],
+                    interpolant.evaluate(&omicron_power)
+                );
+            }
+        }
+
+        // Selecting columns is equivalent to interpolating them individually.
+        let interpolate = |seed, columns: &[usize]| {
+            table
+                .interpolate_selected_columns_with_rng(
+                    &fri_domain,
+                    omicron,
+                    padded_height,
+                    num_trace_randomizers,
+                    columns,
+                    &mut StdRng::seed_from_u64(seed),
+                )
+                .unwrap()
+        };
+        assert_eq!(
+            interpolate(42, &[0, 1, 2]),
+            table
+                .interpolate_columns_with_rng(
+                    &fri_domain,
+                    omicron,
+                    padded_height,
+                    num_trace_randomizers,
+                    0..3,
+                    &mut StdRng::seed_from_u64(42),
+                )
+                .unwrap()
+        );
+        assert_eq!(interpolate(7, &[5]), interpolate(7, &[5, 0])[..1]);
+    }
+
+    #[test]
+    fn trivial_constraints_test() {
+        let num_variables = 3;
+        let one = BFieldElement::new(1);
+        let variables = MPolynomial::variables(num_variables, one);
+        let zero = MPolynomial::zero(num_variables);
+        let cancelling = variables[0].clone() - variables[0].clone();
+
+        let mut table = Table::new(num_variables, num_variables, vec![], "Test".to_string());
+        assert!(table.trivial_constraints().is_empty());
+
+        table.initial_constraints = Some(vec![variables[0].clone(), zero.clone()]);
+        table.transition_constraints = Some(vec![]);
+        table.terminal_constraints = Some(vec![cancelling, variables[1].clone(), zero]);
+
+        let expected = vec![
+            (ConstraintCategory::Initial, 1),
+            (ConstraintCategory::Terminal, 0),
+            (ConstraintCategory::Terminal, 2),
+        ];
+        assert_eq!(expected, table.trivial_constraints());
+    }
+
+    #[test]
+    fn terminal_residuals_test() {
+        let num_variables = 2;
+        let one = BFieldElement::new(1);
+        let variables = MPolynomial::variables(num_variables, one);
+        let matrix = vec![
+            vec![one, one],
+            vec![BFieldElement::new(5), BFieldElement::new(6)],
+        ];
+
+        let mut table = Table::new(num_variables, num_variables, matrix, "Test".to_string());
+        assert_eq!(None, table.terminal_residuals());
+
+        // x_0 - 5 holds in the last row, x_1 - 5 does not.
+        let five = MPolynomial::from_constant(BFieldElement::new(5), num_variables);
+        table.terminal_constraints = Some(vec![
+            variables[0].clone() - five.clone(),
+            variables[1].clone() - five,
+        ]);
+        assert_eq!(
+            Some(vec![BFieldElement::new(0), one]),
+            table.terminal_residuals()
+        );
+
+        let empty_table = table.with_data(vec![]);
+        assert_eq!(None, empty_table.terminal_residuals());
+    }
+
+    #[test]
+    fn evaluate_initial_and_terminal_constraints_test() {
+        let num_variables = 2;
+        let one = BFieldElement::new(1);
+        let variables = MPolynomial::variables(num_variables, one);
+        let matrix = vec![
+            vec![one, BFieldElement::new(0)],
+            vec![BFieldElement::new(5), BFieldElement::new(6)],
+        ];
+
+        let mut table = Table::new(num_variables, num_variables, matrix, "Test".to_string());
+        assert_eq!(
+            Err(BaseTableError::ConstraintsNotPopulated(
+                ConstraintCategory::Initial
+            )),
+            table.evaluate_initial_constraints()
+        );
+        assert_eq!(
+            Err(BaseTableError::ConstraintsNotPopulated(
+                ConstraintCategory::Terminal
+            )),
+            table.evaluate_terminal_constraints()
+        );
+
+        // The trace must start with x_0 = 1 and x_1 = 0, and end with x_0 = 5.
+        let mpol_one = MPolynomial::from_constant(one, num_variables);
+        let five = MPolynomial::from_constant(BFieldElement::new(5), num_variables);
+        table.initial_constraints =
+            Some(vec![variables[0].clone() - mpol_one, variables[1].clone()]);
+        table.terminal_constraints = Some(vec![variables[0].clone() - five]);
+        let zero = BFieldElement::new(0);
+        assert_eq!(Ok(vec![zero, zero]), table.evaluate_initial_constraints());
+        assert_eq!(Ok(vec![zero]), table.evaluate_terminal_constraints());
+
+        // A wrong first row violates exactly the second initial constraint.
+        table.matrix[0][1] = BFieldElement::new(7);
+        assert_eq!(
+            Ok(vec![zero, BFieldElement::new(7)]),
+            table.evaluate_initial_constraints()
+        );
+
+        let empty_table = table.with_data(vec![]);
+        assert_eq!(
+            Err(BaseTableError::EmptyMatrix),
+            empty_table.evaluate_initial_constraints()
+        );
+        assert_eq!(
+            Err(BaseTableError::EmptyMatrix),
+            empty_table.evaluate_terminal_constraints()
+        );
+    }
+
+    #[test]
+    fn evaluate_transition_constraints_test() {
+        let num_variables = 2;
+        let one = BFieldElement::new(1);
+        let zero = BFieldElement::new(0);
+        let matrix = vec![
+            vec![one, BFieldElement::new(2)],
+            vec![BFieldElement::new(2), one],
+        ];
+        let mut table = Table::new(num_variables, num_variables, matrix, "Test".to_string());
+        assert_eq!(
+            Err(BaseTableError::ConstraintsNotPopulated(
+                ConstraintCategory::Transition
+            )),
+            table.evaluate_transition_constraints(0)
+        );
+
+        // The current x_1 must be the next x_0, and the next x_1 must be the current x_0.
+        let variables = MPolynomial::variables(2 * num_variables, one);
+        table.transition_constraints = Some(vec![
+            variables[1].clone() - variables[2].clone(),
+            variables[3].clone() - variables[0].clone(),
+        ]);
+        assert_eq!(
+            Ok(vec![zero, zero]),
+            table.evaluate_transition_constraints(0)
+        );
+        assert_eq!(
+            Err(BaseTableError::NoSuccessorRow { row: 1, height: 2 }),
+            table.evaluate_transition_constraints(1)
+        );
+
+        table.matrix[1][1] = BFieldElement::new(4);
+        assert_eq!(
+            Ok(vec![zero, BFieldElement::new(3)]),
+            table.evaluate_transition_constraints(0)
+        );
+    }
+
+    #[test]
+    fn infer_widths_from_matrix_test() {
+        let table = dummy_program_table(4);
+        let width = Table::infer_widths_from_matrix(table.data()).unwrap();
+        assert_eq!(table.base_width(), width);
+
+        let empty: Vec<Vec<BFieldElement>> = vec![];
+        assert_eq!(
+            Err(BaseTableError::EmptyMatrix),
+            Table::infer_widths_from_matrix(&empty)
+        );
+
+        let mut ragged = table.data().clone();
+        ragged[2].pop();
+        assert_eq!(
+            Err(BaseTableError::RaggedRow {
+                row_index: 2,
+                expected_width: 3,
+                actual_width: 2
+            }),
+            Table::infer_widths_from_matrix(&ragged)
+        );
+    }
+
+    #[test]
+    fn window_test() {
+        let table = dummy_program_table(8);
+        let window = table.window(2..5, 1..3).unwrap();
+        assert_eq!(3, window.len());
+        for (row_idx, row) in window.iter().enumerate() {
+            assert_eq!(&table.data()[row_idx + 2][1..3], row.as_slice());
+        }
+        assert_eq!(Ok(vec![]), table.window(8..8, 0..3));
+
+        assert_eq!(
+            Err(BaseTableError::WindowOutOfBounds {
+                rows: 6..9,
+                cols: 0..1,
+                height: 8,
+                width: 3
+            }),
+            table.window(6..9, 0..1)
+        );
+        assert!(table.window(0..2, 2..4).is_err());
+    }
+
+    #[test]
+    fn assert_well_formed_test() {
+        assert_eq!(Ok(()), dummy_program_table(0).assert_well_formed());
+        assert_eq!(Ok(()), dummy_program_table(8).assert_well_formed());
+        assert_eq!(
+            Err(BaseTableError::HeightNotPowerOfTwo(6)),
+            dummy_program_table(6).assert_well_formed()
+        );
+
+        let mut ragged_table = dummy_program_table(8);
+        ragged_table.mut_data()[5].push(BFieldElement::new(42));
+        let err = ragged_table.assert_well_formed().unwrap_err();
+        assert_eq!(
+            BaseTableError::UnexpectedRowWidth {
+                row_index: 5,
+                expected_width: 3,
+                actual_width: 4,
+            },
+            err
+        );
+        assert_eq!(
+            "Row 5 has width 4, but the table's width is 3",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn pad_test() {
+        let mut table = dummy_program_table(5);
+        let (_, padding_rows) = table.get_padding_rows();
+        table.pad(16);
+        assert_eq!(16, table.num_rows());
+        assert_eq!(padding_rows[0].as_slice(), table.get_row(5));
+        let addresses = (0..16).map(BFieldElement::new).collect_vec();
+        assert_eq!(addresses, table.column_to_vec(0));
+    }
+
+    #[derive(Debug, Clone)]
+    struct RepeatingTable {
+        inherited_table: Table<BFieldElement>,
+    }
+
+    impl InheritsFromTable<BFieldElement> for RepeatingTable {
+        fn inherited_table(&self) -> &Table<BFieldElement> {
+            &self.inherited_table
+        }
+
+        fn mut_inherited_table(&mut self) -> &mut Table<BFieldElement> {
+            &mut self.inherited_table
+        }
+    }
+
+    impl TableLike<BFieldElement> for RepeatingTable {}
+
+    impl Extendable for RepeatingTable {}
+
+    #[test]
+    fn pad_by_repeating_last_row_test() {
+        let matrix = (0..3)
+            .map(|row| vec![BFieldElement::new(row), BFieldElement::new(row * row)])
+            .collect_vec();
+        let mut table = RepeatingTable {
+            inherited_table: Table::new(2, 2, matrix, "RepeatingTable".to_string()),
+        };
+        table.pad(8);
+        assert_eq!(8, table.num_rows());
+        for row_index in 3..8 {
+            assert_eq!(table.get_row(2), table.get_row(row_index));
+        }
+
+        let mut empty_table = RepeatingTable {
+            inherited_table: Table::new(2, 2, vec![], "EmptyRepeatingTable".to_string()),
+        };
+        empty_table.pad(4);
+        assert_eq!(
+            vec![vec![BFieldElement::zero(); 2]; 4],
+            empty_table.data().clone()
+        );
+    }
+
+    #[cfg(feature = "debug-export")]
+    #[test]
+    fn write_csv_test() {
+        let matrix = vec![
+            vec![BFieldElement::new(1), BFieldElement::new(2)],
+            vec![BFieldElement::new(3), -BFieldElement::one()],
+        ];
+        let table = RepeatingTable {
+            inherited_table: Table::new(2, 2, matrix, "CsvTable".to_string()),
+        };
+        let mut csv = vec![];
+        table.write_csv(&mut csv).unwrap();
+        let expected = format!("0,1\n1,2\n3,{}\n", BFieldElement::QUOTIENT - 1);
+        assert_eq!(expected, String::from_utf8(csv).unwrap());
+
+        let x = XFieldElement::new([4, 5, 6].map(BFieldElement::new));
+        assert_eq!("4 5 6", x.to_csv_field());
+    }
+
+    #[test]
+    fn padding_row_for_program_table_test() {
+        let table = dummy_program_table(5);
+        let last_row = table.data().last().unwrap().clone();
+        let (maybe_index, padding_rows) = table.get_padding_rows();
+        assert_eq!(None, maybe_index);
+        assert_eq!(vec![table.padding_row_for(Some(&last_row))], padding_rows);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot pad table of height 5 to smaller height 4")]
+    fn pad_to_smaller_height_test() {
+        dummy_program_table(5).pad(4);
+    }
+
+    #[test]
+    fn column_degrees_test() {
+        let padded_height = 8;
+        let omicron = derive_omicron::<BFieldElement>(padded_height as u64);
+        let matrix = omicron_domain(omicron, padded_height)
+            .into_iter()
+            .map(|x| vec![x, BFieldElement::new(7), BFieldElement::new(0)])
+            .collect();
+        let table = ProgramTable::new_prover(matrix);
+        let fri_domain = dummy_fri_domain(32);
+
+        assert_eq!(
+            vec![1, 0, -1],
+            table.column_degrees(&fri_domain, omicron, padded_height, 0)
+        );
+        let num_trace_randomizers = 2;
+        let randomized_degree = (padded_height + num_trace_randomizers - 1) as Degree;
+        assert!(table
+            .column_degrees(&fri_domain, omicron, padded_height, num_trace_randomizers)
+            .iter()
+            .all(|&degree| degree <= randomized_degree));
+    }
+
+    #[test]
+    fn column_test() {
+        let table = dummy_program_table(4);
+        for col in 0..3 {
+            let expected = table.data().iter().map(|row| row[col]).collect_vec();
+            assert_eq!(expected, table.column(col).copied().collect_vec());
+            assert_eq!(expected, table.column_to_vec(col));
+        }
+        assert_eq!(
+            [1, 4, 7, 10].map(BFieldElement::new).to_vec(),
+            table.column_to_vec(1)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "column index 3 is out of bounds for width 3")]
+    fn column_out_of_bounds_test() {
+        let _ = dummy_program_table(4).column(3);
+    }
+
+    #[test]
+    fn get_row_and_get_rows_test() {
+        let table = dummy_program_table(4);
+        assert_eq!(4, table.num_rows());
+        assert_eq!(
+            [2, 7, 0].map(BFieldElement::new).as_slice(),
+            table.get_row(2)
+        );
+        let rows = table.get_rows(1..3);
+        assert_eq!(2, rows.len());
+        assert_eq!(table.get_row(1), rows[0].as_slice());
+        assert_eq!(table.get_row(2), rows[1].as_slice());
+        assert!(table.get_rows(4..4).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "row index 4 is out of bounds for 4 rows")]
+    fn get_row_out_of_bounds_test() {
+        dummy_program_table(4).get_row(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "row range 2..5 is out of bounds for 4 rows")]
+    fn get_rows_out_of_bounds_test() {
+        dummy_program_table(4).get_rows(2..5);
+    }
+
+    #[test]
+    #[should_panic(expected = "full width 2 must not be smaller than base width 3")]
+    fn full_width_smaller_than_base_width_is_rejected_test() {
+        let _ = Table::<BFieldElement>::new(3, 2, vec![], "Swapped".to_string());
+    }
+
+    #[test]
+    fn barycentric_evaluate_agrees_with_polynomial_evaluation_test() {
+        let mut rng = rand::thread_rng();
+        let b_fri_domain = dummy_fri_domain(32);
+        let fri_domain = FriDomain {
+            offset: b_fri_domain.offset.lift(),
+            omega: b_fri_domain.omega.lift(),
+            length: b_fri_domain.length,
+        };
+        let polynomial = Polynomial::new(XFieldElement::random_elements(20, &mut rng));
+        let codeword = fri_domain.evaluate(&polynomial);
+
+        for point in XFieldElement::random_elements(5, &mut rng) {
+            assert_eq!(
+                polynomial.evaluate(&point),
+                barycentric_evaluate(&codeword, &fri_domain, point)
+            );
+        }
+
+        let in_domain_point = fri_domain.domain_value(7);
+        assert_eq!(
+            codeword[7],
+            barycentric_evaluate(&codeword, &fri_domain, in_domain_point)
+        );
+    }
+
+    #[test]
+    fn trace_element_contributions_sum_to_interpolant_test() {
+        let padded_height = 8;
+        let table = dummy_program_table(padded_height as u64);
+        let fri_domain = dummy_fri_domain(32);
+        let omicron: BFieldElement = derive_omicron(padded_height as u64);
+        let col = 1;
+
+        let interpolant = table
+            .interpolate_columns(&fri_domain, omicron, padded_height, 0, col..col + 1)
+            .unwrap()
+            .pop()
+            .unwrap();
+        let mut sum_of_contributions = Polynomial::zero();
+        for row in 0..padded_height {
+            let trace = table.trace_element(row, col, &fri_domain, omicron, 0);
+            assert_eq!(table.data()[row][col], trace.value);
+            assert_eq!(omicron.mod_pow_u32(row as u32), trace.omicron_domain_point);
+            assert_eq!(4 * row, trace.codeword_index);
+            assert_eq!(
+                trace.value,
+                trace
+                    .interpolant_contribution
+                    .evaluate(&trace.omicron_domain_point)
+            );
+            assert_eq!(
+                fri_domain.evaluate(&trace.interpolant_contribution),
+                trace.codeword_contribution
+            );
+            sum_of_contributions += trace.interpolant_contribution;
+        }
+        assert_eq!(interpolant, sum_of_contributions);
+
+        let randomized_trace = table.trace_element(3, col, &fri_domain, omicron, 2);
+        assert_eq!(
+            BFieldElement::new(0),
+            randomized_trace
+                .interpolant_contribution
+                .evaluate(&omicron.mod_pow_u32(4))
+        );
+    }
+
+    #[test]
+    fn seeded_interpolation_is_deterministic_test() {
+        let padded_height = 8;
+        let num_trace_randomizers = 4;
+        let table = dummy_program_table(padded_height as u64);
+        let fri_domain = dummy_fri_domain(64);
+        let omicron = derive_omicron(padded_height as u64);
+
+        let interpolate = |seed| {
+            table
+                .interpolate_columns_with_rng(
+                    &fri_domain,
+                    omicron,
+                    padded_height,
+                    num_trace_randomizers,
+                    0..3,
+                    &mut StdRng::seed_from_u64(seed),
+                )
+                .unwrap()
+        };
+        assert_eq!(interpolate(42), interpolate(42));
+        assert_ne!(interpolate(42), interpolate(43));
+
+        let low_degree_extend = |seed| {
+            table
+                .low_degree_extension_with_rng(
+                    &fri_domain,
+                    omicron,
+                    padded_height,
+                    num_trace_randomizers,
+                    0..3,
+                    &mut StdRng::seed_from_u64(seed),
+                )
+                .unwrap()
+        };
+        assert_eq!(low_degree_extend(42), low_degree_extend(42));
+    }
+
+    #[test]
+    fn compute_degree_bounds_rejects_constant_constraints_test() {
+        let full_width = 2;
+        let interpolant_degree = 7;
+        let variables = MPolynomial::<XFieldElement>::variables(full_width, XFieldElement::one());
+        let constraint = variables[0].clone() * variables[1].clone() - variables[0].clone();
+
+        let compute_degree_bounds = |constraints: &[MPolynomial<XFieldElement>]| {
+            <ProgramTable as Extendable>::compute_degree_bounds(
+                constraints,
+                interpolant_degree,
+                full_width,
+            )
+        };
+        assert_eq!(
+            Ok(vec![13]),
+            compute_degree_bounds(std::slice::from_ref(&constraint))
+        );
+
+        let zero = MPolynomial::zero(full_width);
+        assert_eq!(
+            Err(BaseTableError::DegenerateConstraint {
+                constraint_index: 1,
+                degree_bound: -2,
+            }),
+            compute_degree_bounds(&[constraint.clone(), zero])
+        );
+
+        let one = MPolynomial::from_constant(XFieldElement::one(), full_width);
+        assert_eq!(
+            Err(BaseTableError::DegenerateConstraint {
+                constraint_index: 0,
+                degree_bound: -1,
+            }),
+            compute_degree_bounds(&[one, constraint])
+        );
+    }
+
+    #[test]
+    fn extension_with_builds_constraints_for_full_width_test() {
+        let table = dummy_program_table(4);
+        let interpolant_degree = 3;
+
+        let extended_table =
+            table.extension_with(vec![], interpolant_degree, |_| ConstraintSets::default());
+        for degree_bounds in [
+            &extended_table.initial_quotient_degree_bounds,
+            &extended_table.consistency_quotient_degree_bounds,
+            &extended_table.transition_quotient_degree_bounds,
+            &extended_table.terminal_quotient_degree_bounds,
+        ] {
+            assert_eq!(&Some(vec![]), degree_bounds);
+        }
+
+        let mut received_width = None;
+        let extended_table = table.extension_with(vec![], interpolant_degree, |full_width| {
+            received_width = Some(full_width);
+            let one = XFieldElement::one();
+            let variables = MPolynomial::variables(2 * full_width, one);
+            ConstraintSets {
+                transition: vec![variables[0].clone() - variables[full_width].clone()],
+                terminal: vec![MPolynomial::variables(full_width, one)[0].square()],
+                ..ConstraintSets::default()
+            }
+        });
+        assert_eq!(Some(table.full_width()), received_width);
+        assert_eq!(Some(vec![]), extended_table.initial_quotient_degree_bounds);
+        assert_eq!(
+            Some(vec![interpolant_degree - 1]),
+            extended_table.transition_quotient_degree_bounds
+        );
+        assert_eq!(
+            Some(vec![2 * interpolant_degree - 1]),
+            extended_table.terminal_quotient_degree_bounds
+        );
+    }
+
+    #[test]
+    fn extension_of_full_width_matrix_is_well_formed_test() {
+        let table = dummy_program_table(4);
+        let full_width_matrix = vec![vec![XFieldElement::one(); table.full_width()]; 4];
+        let mut ext_table = ExtProgramTable::default();
+        *ext_table.mut_inherited_table() =
+            table.extension_with(full_width_matrix, 3, |_| ConstraintSets::default());
+        assert_eq!(Ok(()), ext_table.assert_well_formed());
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 0 has width 3, but the table's width is 4")]
+    fn extension_of_base_width_matrix_panics_test() {
+        let table = dummy_program_table(4);
+        assert_ne!(table.base_width(), table.full_width());
+        let base_width_matrix = table
+            .data()
+            .iter()
+            .map(|row| row.iter().map(|bfe| bfe.lift()).collect())
+            .collect();
+        table.extension_with(base_width_matrix, 3, |_| ConstraintSets::default());
+    }
+
+    #[test]
+    fn quotient_degree_bound_accessors_test() {
+        let table = dummy_program_table(4);
+        assert_eq!(
+            None,
+            table.inherited_table().initial_quotient_degree_bounds()
+        );
+        assert_eq!(
+            None,
+            table.inherited_table().terminal_quotient_degree_bounds()
+        );
+
+        let interpolant_degree = 7;
+        let full_width = table.full_width();
+        let one = XFieldElement::one();
+        let variables = MPolynomial::variables(full_width, one);
+        let transition_variables = MPolynomial::variables(2 * full_width, one);
+        let extended_table = table.extension(
+            vec![],
+            interpolant_degree,
+            vec![variables[0].clone()],
+            vec![],
+            vec![transition_variables[0].clone() * transition_variables[full_width].clone()],
+            vec![variables[1].square() * variables[2].clone()],
+        );
+
+        let expected_initial: &[Degree] = &[interpolant_degree - 1];
+        let expected_transition: &[Degree] = &[2 * interpolant_degree - 1];
+        let expected_terminal: &[Degree] = &[3 * interpolant_degree - 1];
+        assert_eq!(
+            Some(expected_initial),
+            extended_table.initial_quotient_degree_bounds()
+        );
+        assert_eq!(
+            Some(&[][..]),
+            extended_table.consistency_quotient_degree_bounds()
+        );
+        assert_eq!(
+            Some(expected_transition),
+            extended_table.transition_quotient_degree_bounds()
+        );
+        assert_eq!(
+            Some(expected_terminal),
+            extended_table.terminal_quotient_degree_bounds()
+        );
+    }
+
+    #[test]
+    fn check_constraint_arity_test() {
+        let constraints = vec![
+            MPolynomial::<XFieldElement>::zero(4),
+            MPolynomial::zero(4),
+            MPolynomial::zero(3),
+        ];
+        assert_eq!(Ok(()), check_constraint_arity(&constraints[..2], 4));
+        assert_eq!(Ok(()), check_constraint_arity(&[], 4));
+        assert_eq!(
+            Err(BaseTableError::UnexpectedConstraintArity {
+                constraint_index: 2,
+                expected_variable_count: 4,
+                actual_variable_count: 3,
+            }),
+            check_constraint_arity(&constraints, 4)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "transition constraints: Constraint 1 has 3 variables")]
+    fn extension_rejects_constraints_of_wrong_arity_test() {
+        let table = dummy_program_table(4);
+        let full_width = table.full_width();
+        let variables =
+            MPolynomial::<XFieldElement>::variables(2 * full_width, XFieldElement::one());
+        let transition_constraints = vec![
+            variables[0].clone() - variables[full_width].clone(),
+            MPolynomial::variables(3, XFieldElement::one())[0].clone(),
+        ];
+        table.extension(vec![], 3, vec![], vec![], transition_constraints, vec![]);
+    }
+
+    #[test]
+    fn interpolation_without_trace_randomizers_test() {
+        let padded_height: usize = 8;
+        let table = dummy_program_table(padded_height as u64);
+        let fri_domain = dummy_fri_domain(64);
+        let omicron: BFieldElement = derive_omicron(padded_height as u64);
+        let log_2_padded_height = padded_height.trailing_zeros();
+        let log_2_fri_domain_length = fri_domain.length.trailing_zeros();
+
+        let interpolants = table
+            .interpolate_columns(&fri_domain, omicron, padded_height, 0, 0..3)
+            .unwrap();
+        assert_eq!(
+            interpolants,
+            table
+                .interpolate_columns(&fri_domain, omicron, padded_height, 0, 0..3)
+                .unwrap()
+        );
+
+        let codewords = table
+            .low_degree_extension(&fri_domain, omicron, padded_height, 0, 0..3)
+            .unwrap();
+        for (col, (interpolant, codeword)) in interpolants.iter().zip_eq(codewords).enumerate() {
+            assert!(interpolant.degree() < padded_height as isize);
+
+            let mut coefficients = table.column_to_vec(col);
+            intt(&mut coefficients, omicron, log_2_padded_height);
+            assert_eq!(Polynomial::new(coefficients.clone()), *interpolant);
+
+            let mut offset_coefficients = coefficients
+                .iter()
+                .zip(omicron_domain(fri_domain.offset, padded_height))
+                .map(|(&coefficient, offset_power)| coefficient * offset_power)
+                .collect_vec();
+            offset_coefficients.resize(fri_domain.length, BFieldElement::new(0));
+            ntt(
+                &mut offset_coefficients,
+                fri_domain.omega,
+                log_2_fri_domain_length,
+            );
+            assert_eq!(offset_coefficients, codeword);
         }
     }
 }
@@ -414,7 +414,7 @@ pub trait Quotientable: ExtensionTable + Evaluable {
         padded_height: usize,
         num_trace_randomizers: usize,
     ) -> Vec<Degree> {
-        if let Some(db) = &self.inherited_table().initial_quotient_degree_bounds {
+        if let Some(db) = self.inherited_table().initial_quotient_degree_bounds() {
             db.to_owned()
         } else {
             let interpolant_degree = interpolant_degree(padded_height, num_trace_randomizers);
@@ -432,7 +432,7 @@ pub trait Quotientable: ExtensionTable + Evaluable {
         padded_height: usize,
         num_trace_randomizers: usize,
     ) -> Vec<Degree> {
-        if let Some(db) = &self.inherited_table().consistency_quotient_degree_bounds {
+        if let Some(db) = self.inherited_table().consistency_quotient_degree_bounds() {
             db.to_owned()
         } else {
             let interpolant_degree = interpolant_degree(padded_height, num_trace_randomizers);
@@ -450,7 +450,7 @@ pub trait Quotientable: ExtensionTable + Evaluable {
         padded_height: usize,
         num_trace_randomizers: usize,
     ) -> Vec<Degree> {
-        if let Some(db) = &self.inherited_table().transition_quotient_degree_bounds {
+        if let Some(db) = self.inherited_table().transition_quotient_degree_bounds() {
             db.to_owned()
         } else {
             let interpolant_degree = interpolant_degree(padded_height, num_trace_randomizers);
@@ -468,7 +468,7 @@ pub trait Quotientable: ExtensionTable + Evaluable {
         padded_height: usize,
         num_trace_randomizers: usize,
     ) -> Vec<Degree> {
-        if let Some(db) = &self.inherited_table().terminal_quotient_degree_bounds {
+        if let Some(db) = self.inherited_table().terminal_quotient_degree_bounds() {
             db.to_owned()
         } else {
             let interpolant_degree = interpolant_degree(padded_height, num_trace_randomizers);
@@ -177,8 +177,8 @@ impl OpStackTable {
         padded_height: usize,
         num_trace_randomizers: usize,
     ) -> Self {
-        let base_columns = 0..self.base_width();
-        let codewords = self.low_degree_extension(
+        let base_columns = self.base_columns();
+        let codewords = self.low_degree_extension_or_panic(
             fri_domain,
             omicron,
             padded_height,
@@ -271,8 +271,8 @@ impl ExtOpStackTable {
         num_trace_randomizers: usize,
         base_codewords: &[Vec<BFieldElement>],
     ) -> Self {
-        let ext_columns = self.base_width()..self.full_width();
-        let ext_codewords = self.low_degree_extension(
+        let ext_columns = self.extension_columns();
+        let ext_codewords = self.low_degree_extension_or_panic(
             fri_domain,
             omicron,
             padded_height,
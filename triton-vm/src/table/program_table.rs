@@ -75,21 +75,21 @@ impl InheritsFromTable<XFieldElement> for ExtProgramTable {
 impl TableLike<BFieldElement> for ProgramTable {}
 
 impl Extendable for ProgramTable {
-    fn get_padding_rows(&self) -> (Option<usize>, Vec<Vec<BFieldElement>>) {
+    fn padding_row_for(&self, prev: Option<&[BFieldElement]>) -> Vec<BFieldElement> {
         let zero = BFieldElement::zero();
         let one = BFieldElement::one();
-        if let Some(row) = self.data().last() {
-            let mut padding_row = row.clone();
+        if let Some(row) = prev {
+            let mut padding_row = row.to_vec();
             // address keeps increasing
             padding_row[usize::from(Address)] += one;
             padding_row[usize::from(Instruction)] = zero;
             padding_row[usize::from(IsPadding)] = one;
-            (None, vec![padding_row])
+            padding_row
         } else {
             // Not that it makes much sense to run a program with no instructions.
-            let mut padding_row = [zero; BASE_WIDTH];
+            let mut padding_row = vec![zero; BASE_WIDTH];
             padding_row[usize::from(IsPadding)] = one;
-            (None, vec![padding_row.to_vec()])
+            padding_row
         }
     }
 }
@@ -153,8 +153,8 @@ impl ProgramTable {
         padded_height: usize,
         num_trace_randomizers: usize,
     ) -> Self {
-        let base_columns = 0..self.base_width();
-        let codewords = self.low_degree_extension(
+        let base_columns = self.base_columns();
+        let codewords = self.low_degree_extension_or_panic(
             fri_domain,
             omicron,
             padded_height,
@@ -248,8 +248,8 @@ impl ExtProgramTable {
         num_trace_randomizers: usize,
         base_codewords: &[Vec<BFieldElement>],
     ) -> Self {
-        let ext_columns = self.base_width()..self.full_width();
-        let ext_codewords = self.low_degree_extension(
+        let ext_columns = self.extension_columns();
+        let ext_codewords = self.low_degree_extension_or_panic(
             fri_domain,
             omicron,
             padded_height,
@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use std::fmt::Display;
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::mpolynomial::Degree;
 use twenty_first::shared_math::other::{is_power_of_two, roundup_npo2};
@@ -7,7 +8,9 @@ use twenty_first::shared_math::x_field_element::XFieldElement;
 use twenty_first::timing_reporter::TimingReporter;
 
 use crate::fri_domain::FriDomain;
-use crate::table::base_table::{Extendable, InheritsFromTable};
+use crate::table::base_table::{
+    randomized_trace_length, BaseTableError, Extendable, InheritsFromTable,
+};
 use crate::table::extension_table::DegreeWithOrigin;
 
 use super::base_matrix::BaseMatrices;
@@ -64,6 +67,60 @@ pub enum TableId {
     HashTable,
 }
 
+impl TableId {
+    /// All tables, ordered by their index into per-table collections like a cross-table slice.
+    pub fn all() -> [TableId; NUM_TABLES] {
+        use TableId::*;
+
+        [
+            ProgramTable,
+            InstructionTable,
+            ProcessorTable,
+            OpStackTable,
+            RamTable,
+            JumpStackTable,
+            HashTable,
+        ]
+    }
+
+    /// The position of the table in per-table collections, like a cross-table slice. This is the
+    /// order of `all` and of iterating over an `ExtTableCollection`. Unlike `table_id as usize`,
+    /// the mapping is explicit and does not depend on the order of the enum's variants.
+    pub fn index(&self) -> usize {
+        use TableId::*;
+
+        match self {
+            ProgramTable => 0,
+            InstructionTable => 1,
+            ProcessorTable => 2,
+            OpStackTable => 3,
+            RamTable => 4,
+            JumpStackTable => 5,
+            HashTable => 6,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        use TableId::*;
+
+        match self {
+            ProgramTable => "ProgramTable",
+            InstructionTable => "InstructionTable",
+            ProcessorTable => "ProcessorTable",
+            OpStackTable => "OpStackTable",
+            RamTable => "RamTable",
+            JumpStackTable => "JumpStackTable",
+            HashTable => "HashTable",
+        }
+    }
+}
+
+impl Display for TableId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Convert vector-of-arrays to vector-of-vectors.
 fn to_vec_vecs<T: Sized + Clone, const S: usize>(vector_of_arrays: &[[T; S]]) -> Vec<Vec<T>> {
     vector_of_arrays
@@ -73,7 +130,7 @@ fn to_vec_vecs<T: Sized + Clone, const S: usize>(vector_of_arrays: &[[T; S]]) ->
 }
 
 pub fn interpolant_degree(padded_height: usize, num_trace_randomizers: usize) -> Degree {
-    (padded_height + num_trace_randomizers - 1) as Degree
+    randomized_trace_length(padded_height, num_trace_randomizers) as Degree - 1
 }
 
 pub fn derive_omicron<DataPF: FiniteField>(padded_height: u64) -> DataPF {
@@ -441,6 +498,40 @@ impl ExtTableCollection {
         }
     }
 
+    /// Like `data`, but `None` if the table with the given id holds no data, as is the case for
+    /// the tables of a collection created `for_verifier`.
+    pub fn try_data(&self, table_id: TableId) -> Option<&Vec<Vec<XFieldElement>>> {
+        let data = self.data(table_id);
+        match data.is_empty() {
+            true => None,
+            false => Some(data),
+        }
+    }
+
+    /// The codeword in `column` of the table with the given id. Only meaningful if the collection
+    /// holds codewords, i.e., column-major data. Fails if the table has no such column.
+    pub fn try_column(
+        &self,
+        table_id: TableId,
+        column: usize,
+    ) -> Result<&[XFieldElement], BaseTableError> {
+        let data = self.data(table_id);
+        data.get(column)
+            .map(|codeword| codeword.as_slice())
+            .ok_or_else(|| BaseTableError::ColumnOutOfBounds {
+                name: table_id.to_string(),
+                column,
+                width: data.len(),
+            })
+    }
+
+    /// The degree of the interpolants of every table in the collection. All tables are padded to
+    /// the same `padded_height` and interpolated with the same number of trace randomizers, so
+    /// they share one interpolant degree by construction.
+    pub fn interpolant_degree(&self, num_trace_randomizers: usize) -> Degree {
+        interpolant_degree(self.padded_height, num_trace_randomizers)
+    }
+
     pub fn get_all_base_degree_bounds(&self, num_trace_randomizers: usize) -> Vec<Degree> {
         let sum_base_widths = self.into_iter().map(|table| table.base_width()).sum();
         vec![interpolant_degree(self.padded_height, num_trace_randomizers); sum_base_widths]
@@ -512,6 +603,7 @@ impl<'a> IntoIterator for &'a ExtTableCollection {
 
 #[cfg(test)]
 mod table_collection_tests {
+    use crate::cross_table_arguments::{CrossTableArg, PermArg};
     use crate::table::{
         hash_table, instruction_table, jump_stack_table, op_stack_table, processor_table,
         program_table, ram_table,
@@ -524,6 +616,62 @@ mod table_collection_tests {
         ExtTableCollection::with_padded_height(max_padded_height)
     }
 
+    #[test]
+    fn interpolant_degree_is_uniform_across_tables_test() {
+        let padded_height = 16;
+        let num_trace_randomizers = 4;
+        let ext_tables = ExtTableCollection::with_padded_height(padded_height);
+        let expected = interpolant_degree(padded_height, num_trace_randomizers);
+        assert_eq!(
+            expected,
+            ext_tables.interpolant_degree(num_trace_randomizers)
+        );
+        assert!(ext_tables
+            .get_all_base_degree_bounds(num_trace_randomizers)
+            .iter()
+            .all(|&degree_bound| degree_bound == expected));
+
+        // The bound of a cross-table argument is the shared degree, minus one.
+        for perm_arg in PermArg::all_permutation_arguments() {
+            assert_eq!(
+                expected - 1,
+                perm_arg.quotient_degree_bound(&ext_tables, num_trace_randomizers)
+            );
+        }
+    }
+
+    #[test]
+    fn table_id_index_matches_collection_order_test() {
+        let ext_tables = ExtTableCollection::with_padded_height(2);
+        for ((position, table_id), table) in
+            TableId::all().into_iter().enumerate().zip_eq(&ext_tables)
+        {
+            assert_eq!(position, table_id.index());
+            assert!(
+                table.name().contains(table_id.as_str()),
+                "{table_id} has index {position}, but the table at that position is {}",
+                table.name()
+            );
+        }
+    }
+
+    #[test]
+    fn table_id_all_and_display_test() {
+        let all_table_ids = TableId::all();
+        for (index, table_id) in all_table_ids.into_iter().enumerate() {
+            assert_eq!(index, table_id as usize);
+            assert_eq!(format!("{table_id:?}"), table_id.to_string());
+            assert_eq!(table_id.as_str(), table_id.to_string());
+
+            let round_tripped = all_table_ids
+                .into_iter()
+                .filter(|other| other.to_string() == table_id.to_string())
+                .collect_vec();
+            assert_eq!(vec![table_id], round_tripped);
+        }
+        assert_eq!("ProcessorTable", TableId::ProcessorTable.to_string());
+    }
+
     #[test]
     fn base_table_width_is_correct() {
         let base_matrices = BaseMatrices::default();
@@ -1,4 +1,5 @@
 use crate::proof_item::ProofItem;
+use crate::util::ct_eq;
 use itertools::Itertools;
 use num_traits::One;
 use rayon::iter::{
@@ -38,6 +39,7 @@ pub enum ValidationError {
     LastIterationTooHighDegree,
     BadMerkleRootForFirstCodeword,
     BadMerkleRootForLastCodeword,
+    ChallengeDivergedAtRound(usize),
 }
 
 #[derive(Debug, Clone)]
@@ -215,6 +217,8 @@ where
             // Get challenge
             let challenge_digest = proof_stream.prover_fiat_shamir();
             let alpha: XFieldElement = XFieldElement::sample(&challenge_digest);
+            #[cfg(feature = "debug-challenge")]
+            proof_stream.enqueue(&ProofItem::DebugChallenge(alpha));
 
             let x_offset: Vec<XFieldElement> = subgroup_generator
                 .get_cyclic_group_elements(None)
@@ -314,7 +318,10 @@ where
         &self,
         proof_stream: &mut ProofStream<ProofItem<H>, H>,
         first_codeword_mt_root: &H::Digest,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> Result<(), Box<dyn Error>>
+    where
+        H::T: Copy + Into<u64>,
+    {
         let hasher = H::new();
 
         let (num_rounds, degree_of_last_round) = self.num_rounds();
@@ -330,17 +337,25 @@ where
         let mut alphas: Vec<XFieldElement> = vec![];
 
         let first_root: H::Digest = proof_stream.dequeue()?.as_merkle_root()?;
-        if first_root != *first_codeword_mt_root {
+        if !ct_eq(
+            &first_root.to_sequence(),
+            &first_codeword_mt_root.to_sequence(),
+        ) {
             return Err(Box::new(ValidationError::BadMerkleRootForFirstCodeword));
         }
 
         roots.push(first_root);
         timer.elapsed("Init");
 
-        for _round in 0..num_rounds {
+        #[cfg_attr(not(feature = "debug-challenge"), allow(unused_variables))]
+        for round in 0..num_rounds {
             // Get a challenge from the proof stream
             let challenge = proof_stream.verifier_fiat_shamir();
             let alpha: XFieldElement = XFieldElement::sample(&challenge);
+            #[cfg(feature = "debug-challenge")]
+            if proof_stream.dequeue()?.as_debug_challenge()? != alpha {
+                return Err(Box::new(ValidationError::ChallengeDivergedAtRound(round)));
+            }
             alphas.push(alpha);
 
             let root: H::Digest = proof_stream.dequeue()?.as_merkle_root()?;
@@ -349,7 +364,8 @@ where
         timer.elapsed("Roots and alpha");
 
         // Extract last codeword
-        let last_codeword: Vec<XFieldElement> = proof_stream.dequeue()?.as_fri_codeword()?;
+        let last_codeword_item = proof_stream.dequeue()?;
+        let last_codeword = last_codeword_item.as_fri_codeword_ref()?;
 
         // Check if last codeword matches the given root
         let codeword_digests = last_codeword
@@ -358,7 +374,10 @@ where
             .collect_vec();
         let last_codeword_mt = MerkleTree::<H>::from_digests(&codeword_digests);
         let last_root = roots.last().unwrap();
-        if *last_root != last_codeword_mt.get_root() {
+        if !ct_eq(
+            &last_root.to_sequence(),
+            &last_codeword_mt.get_root().to_sequence(),
+        ) {
             return Err(Box::new(ValidationError::BadMerkleRootForLastCodeword));
         }
 
@@ -374,7 +393,7 @@ where
             last_codeword.len(),
             log_2_of_n
         );
-        let mut last_polynomial = last_codeword.clone();
+        let mut last_polynomial = last_codeword.to_vec();
         let last_omega = self.domain.omega.mod_pow_u32(2u32.pow(num_rounds as u32));
         intt::<XFieldElement>(&mut last_polynomial, last_omega, log_2_of_n);
 
@@ -623,6 +642,26 @@ mod triton_xfri_tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "debug-challenge")]
+    fn diverging_fri_challenge_is_reported_test() {
+        type Hasher = RescuePrimeRegular;
+
+        let fri: Fri<Hasher> = get_x_field_fri_test_object(1024, 4, 6);
+        let mut proof_stream: ProofStream<ProofItem<Hasher>, Hasher> = ProofStream::default();
+        let first_root = Hasher::new().hash_sequence(&[BFieldElement::new(42)]);
+        proof_stream.enqueue(&ProofItem::MerkleRoot(first_root));
+        let transcript_length = proof_stream.transcript_length();
+        proof_stream.enqueue(&ProofItem::DebugChallenge(XFieldElement::zero()));
+        assert_eq!(transcript_length, proof_stream.transcript_length());
+
+        let err = fri.verify(&mut proof_stream, &first_root).unwrap_err();
+        assert_eq!(
+            Some(&ValidationError::ChallengeDivergedAtRound(0)),
+            err.downcast_ref::<ValidationError>()
+        );
+    }
+
     #[test]
     fn prove_and_verify_low_degree_of_twice_cubing_plus_one() {
         type Hasher = RescuePrimeRegular;
@@ -0,0 +1,57 @@
+/// Whether `a` and `b` are equal, compared in constant time with respect to their elements: every
+/// pair of elements is inspected, even after a mismatch is found. Hence, the running time does not
+/// reveal the position of the first mismatch. The lengths are not considered secret; slices of
+/// different length are unequal without any element being inspected. Generic over the element
+/// type, such that digests of both field-oriented and byte-oriented hashers can be compared.
+pub fn ct_eq<T: Copy + Into<u64>>(a: &[T], b: &[T]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference = a
+        .iter()
+        .zip(b.iter())
+        .fold(0, |acc, (&x, &y)| acc | (x.into() ^ y.into()));
+    std::hint::black_box(difference) == 0
+}
+
+#[cfg(test)]
+mod util_tests {
+    use rand::{Rng, RngCore};
+    use twenty_first::shared_math::b_field_element::BFieldElement;
+    use twenty_first::shared_math::traits::GetRandomElements;
+
+    use super::*;
+
+    #[test]
+    fn ct_eq_agrees_with_eq_test() {
+        let mut rng = rand::thread_rng();
+        for length in [0, 1, 5, 32] {
+            let a = BFieldElement::random_elements(length, &mut rng);
+            assert!(ct_eq(&a, &a.clone()));
+
+            let longer = [a.clone(), vec![BFieldElement::new(0)]].concat();
+            assert_eq!(a == longer, ct_eq(&a, &longer));
+            assert_eq!(longer == a, ct_eq(&longer, &a));
+
+            if length == 0 {
+                continue;
+            }
+            let mut b = a.clone();
+            let mismatch_index = rng.gen_range(0..length);
+            b[mismatch_index] = BFieldElement::new(rng.next_u64());
+            assert_eq!(a == b, ct_eq(&a, &b));
+
+            let c = BFieldElement::random_elements(length, &mut rng);
+            assert_eq!(a == c, ct_eq(&a, &c));
+        }
+    }
+
+    #[test]
+    fn ct_eq_on_bytes_test() {
+        let a = *blake3::hash(b"a").as_bytes();
+        let b = *blake3::hash(b"b").as_bytes();
+        assert!(ct_eq(&a, &a));
+        assert!(!ct_eq(&a, &b));
+        assert!(!ct_eq(&a, &a[1..]));
+    }
+}